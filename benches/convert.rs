@@ -72,3 +72,29 @@ fn from_io_error_kind_to_exit_code(b: &mut Bencher) {
 
     b.iter(|| ExitCode::from(io::ErrorKind::NotFound));
 }
+
+#[cfg(feature = "std")]
+const IO_ERROR_KINDS: [std::io::ErrorKind; 8] = [
+    std::io::ErrorKind::NotFound,
+    std::io::ErrorKind::PermissionDenied,
+    std::io::ErrorKind::ConnectionRefused,
+    std::io::ErrorKind::BrokenPipe,
+    std::io::ErrorKind::AlreadyExists,
+    std::io::ErrorKind::InvalidData,
+    std::io::ErrorKind::UnexpectedEof,
+    std::io::ErrorKind::Other,
+];
+
+#[cfg(feature = "std")]
+#[bench]
+fn from_io_error_to_exit_code_with_various_kinds(b: &mut Bencher) {
+    use std::io::Error;
+
+    b.iter(|| IO_ERROR_KINDS.map(|kind| ExitCode::from(Error::from(test::black_box(kind)))));
+}
+
+#[cfg(feature = "std")]
+#[bench]
+fn from_io_error_kind_to_exit_code_with_various_kinds(b: &mut Bencher) {
+    b.iter(|| IO_ERROR_KINDS.map(|kind| ExitCode::from(test::black_box(kind))));
+}
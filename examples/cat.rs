@@ -4,11 +4,12 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 //! An example of concatenating files and print on the standard output. The
-//! contents of the file must be valid UTF-8.
+//! files are processed one at a time, and an error is reported for each file
+//! which could not be printed.
 
 use std::{
-    fs,
-    io::{self, Read},
+    fs::File,
+    io::{self, Read, Write},
     path::PathBuf,
     process::ExitCode,
 };
@@ -22,30 +23,65 @@ struct Opt {
     ///
     /// If [FILE] is not specified, data will be read from standard input.
     #[arg(value_name("FILE"))]
-    pub input: Option<Vec<PathBuf>>,
+    pub input: Vec<PathBuf>,
+}
+
+/// Copies `reader` to `writer`, distinguishing an error while reading the input
+/// from an error while writing the output.
+fn copy(reader: &mut impl Read, writer: &mut impl Write) -> sysexits::Result<()> {
+    let mut buf = [0; 8192];
+    loop {
+        let len = match reader.read(&mut buf) {
+            Ok(0) => return Ok(()),
+            Ok(len) => len,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => {
+                eprintln!("Error: {err}");
+                // An error while reading an input is converted with the
+                // `From<io::Error>` implementation (e.g., `InvalidData` is
+                // converted into `DataErr`).
+                return Err(err.into());
+            }
+        };
+        if let Err(err) = writer.write_all(&buf[..len]) {
+            eprintln!("Error: {err}");
+            // An error while writing an output is converted with the dedicated
+            // function, since the output is not an input file.
+            return Err(sysexits::ExitCode::from_io_error_for_output(&err));
+        }
+    }
+}
+
+/// Prints the file at `path` to `writer`.
+fn cat(path: &PathBuf, writer: &mut impl Write) -> sysexits::Result<()> {
+    // An error while opening the file is converted with the `From<io::Error>`
+    // implementation:
+    //
+    // - `NotFound` (the file does not exist) is converted into `NoInput`.
+    // - `PermissionDenied` (the file is not readable) is converted into `NoPerm`.
+    // - Any other error is converted into the corresponding variant, which is
+    //   `IoErr` by default.
+    let mut file = File::open(path).map_err(|err| {
+        eprintln!("Error: {}: {err}", path.display());
+        sysexits::ExitCode::from(err)
+    })?;
+    copy(&mut file, writer)
 }
 
 fn main() -> ExitCode {
     let opt = Opt::parse();
 
-    let contents: io::Result<Vec<_>> = if let Some(files) = opt.input {
-        files.into_iter().map(fs::read_to_string).collect()
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    let result = if opt.input.is_empty() {
+        copy(&mut io::stdin().lock(), &mut stdout)
     } else {
-        let mut buf = String::new();
-        vec![io::stdin().read_to_string(&mut buf).map(|_| buf)]
-            .into_iter()
-            .collect()
-    };
-    let contents = match contents {
-        Ok(strings) => strings,
-        Err(err) => {
-            eprintln!("Error: {err}");
-            return sysexits::ExitCode::from(err).into();
-        }
+        // Like `cat(1)`, continue with the remaining files after an error, and
+        // exit with the code of the first error.
+        opt.input
+            .iter()
+            .map(|path| cat(path, &mut stdout))
+            .fold(Ok(()), Result::and)
     };
-
-    for output in contents {
-        print!("{output}");
-    }
-    ExitCode::SUCCESS
+    sysexits::ExitCode::from(result).into()
 }
@@ -6,10 +6,10 @@
 //! or the standard input.
 
 use std::{
-    fs,
+    fmt, fs,
     io::{self, Read},
     path::PathBuf,
-    str,
+    str::{self, Utf8Error},
 };
 
 use clap::Parser;
@@ -25,30 +25,45 @@ struct Opt {
     pub input: Option<PathBuf>,
 }
 
-fn main() -> ExitCode {
-    let opt = Opt::parse();
+#[derive(Debug)]
+enum Error {
+    Io(io::Error),
+    Utf8(Utf8Error),
+}
 
-    let input = if let Some(file) = opt.input {
-        match fs::read(file) {
-            Ok(data) => data,
-            Err(err) => {
-                eprintln!("Error: {err}");
-                return ExitCode::from(err);
-            }
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => err.fmt(f),
+            Self::Utf8(err) => err.fmt(f),
         }
+    }
+}
+
+impl From<Error> for ExitCode {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Io(err) => err.into(),
+            Error::Utf8(_) => Self::DataErr,
+        }
+    }
+}
+
+fn run(opt: Opt) -> Result<(), Error> {
+    let input = if let Some(file) = opt.input {
+        fs::read(file).map_err(Error::Io)?
     } else {
         let mut buf = Vec::new();
-        if let Err(err) = io::stdin().read_to_end(&mut buf) {
-            eprintln!("Error: {err}");
-            return ExitCode::from(err);
-        }
+        io::stdin().read_to_end(&mut buf).map_err(Error::Io)?;
         buf
     };
-    if let Err(err) = str::from_utf8(&input) {
-        eprintln!("Error: {err}");
-        ExitCode::DataErr
-    } else {
-        println!("OK");
-        ExitCode::Ok
-    }
+    str::from_utf8(&input).map_err(Error::Utf8)?;
+    println!("OK");
+    Ok(())
+}
+
+fn main() {
+    let opt = Opt::parse();
+
+    ExitCode::exit_from_result(run(opt));
 }
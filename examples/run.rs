@@ -0,0 +1,44 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An example of parsing an integer from the command line argument. The
+//! process exit code is computed by `sysexits::ExitCode::run`.
+
+use std::num::ParseIntError;
+
+use clap::Parser;
+use sysexits::ExitCode;
+
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Opt {
+    /// Integer to double.
+    #[arg(value_name("INTEGER"))]
+    pub input: String,
+}
+
+/// The error type of this program.
+#[derive(Debug)]
+struct Error(ParseIntError);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid integer: {}", self.0)
+    }
+}
+
+impl From<Error> for ExitCode {
+    fn from(_: Error) -> Self {
+        Self::DataErr
+    }
+}
+
+fn main() -> std::process::ExitCode {
+    let opt = Opt::parse();
+    ExitCode::run(|| -> Result<(), Error> {
+        let n = opt.input.parse::<i64>().map_err(Error)?;
+        println!("{}", n.saturating_mul(2));
+        Ok(())
+    })
+}
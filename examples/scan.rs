@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An example of scanning a directory recursively and reading every file in
+//! it. The exit code is the most severe of the exit codes of the entries, as
+//! ranked by `ExitCode::severity`.
+
+use std::{env, fs, io, path::Path};
+
+use sysexits::ExitCode;
+
+/// Reads the file at `path` to the end, and returns the exit code of this
+/// entry.
+fn check_file(path: &Path) -> ExitCode {
+    let result = fs::File::open(path).and_then(|mut file| io::copy(&mut file, &mut io::sink()));
+    match result {
+        Ok(_) => {
+            println!("{}: ok", path.display());
+            ExitCode::Ok
+        }
+        Err(err) => {
+            // The conversion from `io::Error` selects the exit code:
+            //
+            // - A file which vanished during the scan is `ExitCode::NoInput`.
+            // - A file which cannot be opened due to the lack of permissions is
+            //   `ExitCode::NoPerm`.
+            // - Any other failure to read a file is usually `ExitCode::IoErr`.
+            let code = ExitCode::from(&err);
+            println!("{}: {err} ({})", path.display(), code.name());
+            code
+        }
+    }
+}
+
+/// Scans the directory at `path` recursively, and returns the exit codes of
+/// all entries.
+fn scan(path: &Path, codes: &mut Vec<ExitCode>) {
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(err) => {
+            // A directory which cannot be listed is handled in the same way as
+            // a file which cannot be read.
+            let code = ExitCode::from(&err);
+            println!("{}: {err} ({})", path.display(), code.name());
+            codes.push(code);
+            return;
+        }
+    };
+    for entry in entries {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) => {
+                // A failure while iterating over the directory itself is
+                // `ExitCode::IoErr` in most cases.
+                let code = ExitCode::from(&err);
+                println!("{}: {err} ({})", path.display(), code.name());
+                codes.push(code);
+                continue;
+            }
+        };
+        let path = entry.path();
+        match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => scan(&path, codes),
+            Ok(_) => codes.push(check_file(&path)),
+            Err(err) => codes.push(ExitCode::from(err)),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args_os().skip(1);
+    let (Some(dir), None) = (args.next(), args.next()) else {
+        eprintln!("Usage: scan <DIRECTORY>");
+        // Wrong number of arguments is `ExitCode::Usage`.
+        return ExitCode::Usage;
+    };
+
+    let mut codes = Vec::new();
+    scan(Path::new(&dir), &mut codes);
+
+    // The most severe exit code, as ranked by `ExitCode::severity`, is
+    // reported. For example, if one entry is `ExitCode::TempFail` (75) and
+    // another is `ExitCode::Software` (70), the exit code is 70. If the
+    // directory is empty, this is `ExitCode::Ok`.
+    let code = ExitCode::worst(codes);
+    if code.is_failure() {
+        eprintln!("Error: {}", code.description());
+    }
+    code
+}
@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An example of counting the number of lines in a file. The input is a file or
+//! the standard input.
+
+use std::{
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use clap::Parser;
+use sysexits::{ExitCode, MainResult, Result};
+
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Opt {
+    /// File to count.
+    ///
+    /// If [FILE] is not specified, data will be read from standard input.
+    #[arg(value_name("FILE"))]
+    pub input: Option<PathBuf>,
+}
+
+fn run(opt: Opt) -> Result<()> {
+    let input = match opt.input {
+        Some(path) if path.to_str().unwrap_or_default() != "-" => fs::read(path),
+        _ => {
+            let mut buf = Vec::new();
+            io::stdin().read_to_end(&mut buf).map(|_| buf)
+        }
+    }
+    .map_err(|err| {
+        eprintln!("Error: {err}");
+        ExitCode::from(err)
+    })?;
+    let lines = input.iter().fold(0, |n, &b| n + usize::from(b == b'\n'));
+    println!("{lines}");
+    Ok(())
+}
+
+fn main() -> MainResult {
+    let opt = Opt::parse();
+    run(opt).into()
+}
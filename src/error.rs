@@ -9,19 +9,180 @@ use core::fmt;
 
 /// The error type indicating that [`ExitCode`](crate::ExitCode) was out of
 /// range.
+///
+/// Use [`ExitCodeRangeError::value`] to get the value which was out of range.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[allow(clippy::module_name_repetitions)]
-pub struct ExitCodeRangeError;
+pub struct ExitCodeRangeError(i128);
+
+impl ExitCodeRangeError {
+    #[inline]
+    pub(crate) const fn new(value: i128) -> Self {
+        Self(value)
+    }
+
+    /// Returns the value which was out of range.
+    ///
+    /// The value is widened to [`i128`] to cover all integer types. A [`u128`]
+    /// value greater than [`i128::MAX`] is saturated to [`i128::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::try_from(79_u8).unwrap_err().value(), 79);
+    /// assert_eq!(ExitCode::try_from(-1_i32).unwrap_err().value(), -1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn value(&self) -> i128 {
+        self.0
+    }
+}
 
 impl fmt::Display for ExitCodeRangeError {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "value is out of range for `ExitCode`")
+        write!(f, "value `{}` is out of range for `ExitCode`", self.value())
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ExitCodeRangeError {}
+impl core::error::Error for ExitCodeRangeError {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ExitCodeRangeError {
+    /// Serializes this error as the value which was out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let err = ExitCode::try_from(79_u8).unwrap_err();
+    /// assert_eq!(serde_json::to_string(&err).unwrap(), "79");
+    /// ```
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i128(self.value())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ExitCodeRangeError {
+    /// Deserializes an error from the value which was out of range.
+    ///
+    /// Returns an error if the value is a valid exit code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{error::ExitCodeRangeError, ExitCode};
+    /// #
+    /// let err = serde_json::from_str::<ExitCodeRangeError>("79").unwrap();
+    /// assert_eq!(err, ExitCode::try_from(79_u8).unwrap_err());
+    ///
+    /// assert!(serde_json::from_str::<ExitCodeRangeError>("64").is_err());
+    /// ```
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, Unexpected};
+
+        let value = i128::deserialize(deserializer)?;
+        match crate::ExitCode::try_from(value) {
+            Ok(_) => Err(D::Error::invalid_value(
+                Unexpected::Other("a valid exit code"),
+                &"a value which is out of range for `ExitCode`",
+            )),
+            Err(err) => Ok(err),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an
+/// [`ExitCode`](crate::ExitCode) from a string.
+///
+/// Use [`ParseExitCodeError::kind`] to get the cause of the error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ParseExitCodeError(ParseExitCodeErrorKind);
+
+impl ParseExitCodeError {
+    #[inline]
+    pub(crate) const fn new(kind: ParseExitCodeErrorKind) -> Self {
+        Self(kind)
+    }
+
+    /// Returns the cause of this error.
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> ParseExitCodeErrorKind {
+        self.0
+    }
+}
+
+impl fmt::Display for ParseExitCodeError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind() {
+            ParseExitCodeErrorKind::NotANumber => write!(f, "exit code is not a number"),
+            ParseExitCodeErrorKind::OutOfRange => {
+                write!(f, "value is out of range for `ExitCode`")
+            }
+            ParseExitCodeErrorKind::UnknownName => write!(f, "unknown exit code name"),
+        }
+    }
+}
+
+impl core::error::Error for ParseExitCodeError {}
+
+/// The kinds of errors which can cause [`ParseExitCodeError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[allow(clippy::module_name_repetitions)]
+#[non_exhaustive]
+pub enum ParseExitCodeErrorKind {
+    /// The input is empty or contains a character which is not an ASCII
+    /// digit.
+    ///
+    /// When parsing from a string, which also accepts names, this is only
+    /// returned if the input is empty or starts with an ASCII digit, `+` or
+    /// `-`. For example, `6x` and `+64` are not a number.
+    NotANumber,
+
+    /// The input is a number, but is not a valid exit code.
+    OutOfRange,
+
+    /// The input does not start with an ASCII digit, `+` or `-`, and is
+    /// neither a variant name nor a macro name.
+    ///
+    /// This is only returned when parsing from a string.
+    UnknownName,
+}
+
+/// The error type indicating that [`ExitCode::Ok`](crate::ExitCode::Ok) was
+/// converted into a nonzero integer type.
+///
+/// This is returned by the conversion from [`ExitCode`](crate::ExitCode) into
+/// [`NonZeroU8`](core::num::NonZeroU8).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SuccessIsZeroError(());
+
+impl SuccessIsZeroError {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self(())
+    }
+}
+
+impl fmt::Display for SuccessIsZeroError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`ExitCode::Ok` cannot be represented as a nonzero value")
+    }
+}
+
+impl core::error::Error for SuccessIsZeroError {}
 
 #[cfg(feature = "std")]
 /// An error which can be returned when converting an
@@ -63,46 +224,277 @@ impl fmt::Display for TryFromExitStatusError {
 #[cfg(feature = "std")]
 impl std::error::Error for TryFromExitStatusError {}
 
+#[cfg(all(feature = "serde", feature = "std"))]
+impl serde::Serialize for TryFromExitStatusError {
+    /// Serializes this error as the corresponding exit code, or as none if the
+    /// process was terminated by a signal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::process::Command;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// # #[cfg(unix)]
+    /// # {
+    /// let status = Command::new("sh").args(["-c", "exit 1"]).status().unwrap();
+    /// let err = ExitCode::try_from(status).unwrap_err();
+    /// assert_eq!(serde_json::to_string(&err).unwrap(), "1");
+    /// # }
+    /// ```
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.code().serialize(serializer)
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<'de> serde::Deserialize<'de> for TryFromExitStatusError {
+    /// Deserializes an error from the corresponding exit code, or from none if
+    /// the process was terminated by a signal.
+    ///
+    /// Returns an error if the exit code is a valid exit code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::error::TryFromExitStatusError;
+    /// #
+    /// let err = serde_json::from_str::<TryFromExitStatusError>("1").unwrap();
+    /// assert_eq!(err.code(), Some(1));
+    ///
+    /// let err = serde_json::from_str::<TryFromExitStatusError>("null").unwrap();
+    /// assert_eq!(err.code(), None);
+    ///
+    /// assert!(serde_json::from_str::<TryFromExitStatusError>("64").is_err());
+    /// ```
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, Unexpected};
+
+        let code = Option::<i32>::deserialize(deserializer)?;
+        match code.map(crate::ExitCode::try_from) {
+            Some(Ok(_)) => Err(D::Error::invalid_value(
+                Unexpected::Other("a valid exit code"),
+                &"an exit code which is not `0` or `64..=78`",
+            )),
+            _ => Ok(Self::new(code)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn clone_exit_code_range_error() {
-        assert_eq!(ExitCodeRangeError.clone(), ExitCodeRangeError);
+        assert_eq!(
+            ExitCodeRangeError::new(79).clone(),
+            ExitCodeRangeError::new(79)
+        );
     }
 
     #[test]
     fn copy_exit_code_range_error() {
-        let a = ExitCodeRangeError;
+        let a = ExitCodeRangeError::new(79);
         let b = a;
         assert_eq!(a, b);
     }
 
     #[test]
     fn debug_exit_code_range_error() {
-        assert_eq!(format!("{ExitCodeRangeError:?}"), "ExitCodeRangeError");
+        assert_eq!(
+            format!("{:?}", ExitCodeRangeError::new(79)),
+            "ExitCodeRangeError(79)"
+        );
     }
 
     #[test]
     fn exit_code_range_error_equality() {
-        assert_eq!(ExitCodeRangeError, ExitCodeRangeError);
+        assert_eq!(ExitCodeRangeError::new(79), ExitCodeRangeError::new(79));
+        assert_ne!(ExitCodeRangeError::new(79), ExitCodeRangeError::new(-1));
+    }
+
+    #[test]
+    fn value_exit_code_range_error() {
+        assert_eq!(ExitCodeRangeError::new(1).value(), 1);
+        assert_eq!(ExitCodeRangeError::new(79).value(), 79);
+        assert_eq!(ExitCodeRangeError::new(-1).value(), -1);
+        assert_eq!(ExitCodeRangeError::new(i128::MIN).value(), i128::MIN);
+        assert_eq!(ExitCodeRangeError::new(i128::MAX).value(), i128::MAX);
+    }
+
+    #[test]
+    const fn value_exit_code_range_error_is_const_fn() {
+        const _: i128 = ExitCodeRangeError::new(79).value();
     }
 
     #[test]
     fn display_exit_code_range_error() {
         assert_eq!(
-            format!("{ExitCodeRangeError}"),
-            "value is out of range for `ExitCode`"
+            format!("{}", ExitCodeRangeError::new(79)),
+            "value `79` is out of range for `ExitCode`"
+        );
+        assert_eq!(
+            format!("{}", ExitCodeRangeError::new(-1)),
+            "value `-1` is out of range for `ExitCode`"
         );
     }
 
-    #[cfg(feature = "std")]
     #[test]
     fn source_exit_code_range_error() {
-        use std::error::Error;
+        use core::error::Error;
 
-        assert!(ExitCodeRangeError.source().is_none());
+        assert!(ExitCodeRangeError::new(79).source().is_none());
+    }
+
+    #[test]
+    fn clone_parse_exit_code_error() {
+        let err = ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange);
+        assert_eq!(err.clone(), err);
+    }
+
+    #[test]
+    fn copy_parse_exit_code_error() {
+        let a = ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange);
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug_parse_exit_code_error() {
+        assert_eq!(
+            format!(
+                "{:?}",
+                ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber)
+            ),
+            "ParseExitCodeError(NotANumber)"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange)
+            ),
+            "ParseExitCodeError(OutOfRange)"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                ParseExitCodeError::new(ParseExitCodeErrorKind::UnknownName)
+            ),
+            "ParseExitCodeError(UnknownName)"
+        );
+    }
+
+    #[test]
+    fn parse_exit_code_error_equality() {
+        assert_eq!(
+            ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange),
+            ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange)
+        );
+        assert_ne!(
+            ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange),
+            ParseExitCodeError::new(ParseExitCodeErrorKind::UnknownName)
+        );
+    }
+
+    #[test]
+    fn kind_parse_exit_code_error() {
+        assert_eq!(
+            ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber).kind(),
+            ParseExitCodeErrorKind::NotANumber
+        );
+        assert_eq!(
+            ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange).kind(),
+            ParseExitCodeErrorKind::OutOfRange
+        );
+        assert_eq!(
+            ParseExitCodeError::new(ParseExitCodeErrorKind::UnknownName).kind(),
+            ParseExitCodeErrorKind::UnknownName
+        );
+    }
+
+    #[test]
+    const fn kind_parse_exit_code_error_is_const_fn() {
+        const _: ParseExitCodeErrorKind =
+            ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber).kind();
+    }
+
+    #[test]
+    fn display_parse_exit_code_error() {
+        assert_eq!(
+            format!(
+                "{}",
+                ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber)
+            ),
+            "exit code is not a number"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange)
+            ),
+            "value is out of range for `ExitCode`"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ParseExitCodeError::new(ParseExitCodeErrorKind::UnknownName)
+            ),
+            "unknown exit code name"
+        );
+    }
+
+    #[test]
+    fn source_parse_exit_code_error() {
+        use core::error::Error;
+
+        assert!(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber)
+            .source()
+            .is_none());
+        assert!(ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange)
+            .source()
+            .is_none());
+        assert!(ParseExitCodeError::new(ParseExitCodeErrorKind::UnknownName)
+            .source()
+            .is_none());
+    }
+
+    #[test]
+    fn clone_success_is_zero_error() {
+        assert_eq!(SuccessIsZeroError::new().clone(), SuccessIsZeroError::new());
+    }
+
+    #[test]
+    fn copy_success_is_zero_error() {
+        let a = SuccessIsZeroError::new();
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug_success_is_zero_error() {
+        assert_eq!(
+            format!("{:?}", SuccessIsZeroError::new()),
+            "SuccessIsZeroError(())"
+        );
+    }
+
+    #[test]
+    fn display_success_is_zero_error() {
+        assert_eq!(
+            format!("{}", SuccessIsZeroError::new()),
+            "`ExitCode::Ok` cannot be represented as a nonzero value"
+        );
+    }
+
+    #[test]
+    fn source_success_is_zero_error() {
+        use core::error::Error;
+
+        assert!(SuccessIsZeroError::new().source().is_none());
     }
 
     #[cfg(feature = "std")]
@@ -201,4 +593,93 @@ mod tests {
         assert!(TryFromExitStatusError::new(Some(1)).source().is_none());
         assert!(TryFromExitStatusError::new(None).source().is_none());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_exit_code_range_error() {
+        assert_eq!(
+            serde_json::to_string(&ExitCodeRangeError::new(79)).unwrap(),
+            "79"
+        );
+        assert_eq!(
+            serde_json::to_string(&ExitCodeRangeError::new(-1)).unwrap(),
+            "-1"
+        );
+        assert_eq!(
+            serde_json::to_string(&ExitCodeRangeError::new(i128::MAX)).unwrap(),
+            format!("{}", i128::MAX)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_exit_code_range_error() {
+        assert_eq!(
+            serde_json::from_str::<ExitCodeRangeError>("79").unwrap(),
+            ExitCodeRangeError::new(79)
+        );
+        assert_eq!(
+            serde_json::from_str::<ExitCodeRangeError>("-1").unwrap(),
+            ExitCodeRangeError::new(-1)
+        );
+        assert!(serde_json::from_str::<ExitCodeRangeError>("0").is_err());
+        assert!(serde_json::from_str::<ExitCodeRangeError>("64").is_err());
+        assert!(serde_json::from_str::<ExitCodeRangeError>("78").is_err());
+        assert!(serde_json::from_str::<ExitCodeRangeError>(r#""79""#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn exit_code_range_error_round_trip() {
+        for value in [-1, 1, 63, 79, 255, i128::MIN, i128::MAX] {
+            let err = ExitCodeRangeError::new(value);
+            let json = serde_json::to_string(&err).unwrap();
+            assert_eq!(
+                serde_json::from_str::<ExitCodeRangeError>(&json).unwrap(),
+                err
+            );
+        }
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn serialize_try_from_exit_status_error() {
+        assert_eq!(
+            serde_json::to_string(&TryFromExitStatusError::new(Some(1))).unwrap(),
+            "1"
+        );
+        assert_eq!(
+            serde_json::to_string(&TryFromExitStatusError::new(None)).unwrap(),
+            "null"
+        );
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn deserialize_try_from_exit_status_error() {
+        assert_eq!(
+            serde_json::from_str::<TryFromExitStatusError>("1").unwrap(),
+            TryFromExitStatusError::new(Some(1))
+        );
+        assert_eq!(
+            serde_json::from_str::<TryFromExitStatusError>("null").unwrap(),
+            TryFromExitStatusError::new(None)
+        );
+        assert!(serde_json::from_str::<TryFromExitStatusError>("0").is_err());
+        assert!(serde_json::from_str::<TryFromExitStatusError>("64").is_err());
+        assert!(serde_json::from_str::<TryFromExitStatusError>("78").is_err());
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn try_from_exit_status_error_round_trip() {
+        for code in [Some(-1), Some(1), Some(63), Some(79), Some(255), None] {
+            let err = TryFromExitStatusError::new(code);
+            let json = serde_json::to_string(&err).unwrap();
+            assert_eq!(
+                serde_json::from_str::<TryFromExitStatusError>(&json).unwrap(),
+                err
+            );
+        }
+    }
 }
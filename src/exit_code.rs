@@ -7,16 +7,73 @@
 //!
 //! [`<sysexits.h>`]: https://man.openbsd.org/sysexits
 
+#[cfg(feature = "anyhow")]
+mod anyhow;
+pub mod category;
+mod cmp;
 mod consts;
 mod convert;
+pub mod descriptor;
+#[cfg(feature = "miette")]
+pub mod diagnostic;
+#[cfg(feature = "std")]
+pub mod exit;
+#[cfg(feature = "alloc")]
+pub mod exit_error;
 mod fmt;
+#[cfg(feature = "std")]
+pub mod io_error_mapper;
+pub mod iter;
+#[cfg(feature = "std")]
+pub mod main_result;
+pub mod names;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "std")]
+pub mod report;
 pub mod result;
+#[cfg(feature = "serde")]
+mod serde;
+pub mod set;
+#[cfg(feature = "anstyle")]
+mod style;
+pub mod summary;
+#[cfg(feature = "tracing")]
+mod tracing;
+
+use crate::error::{ParseExitCodeError, ParseExitCodeErrorKind};
 
 /// `ExitCode` is a type that represents the system exit code constants as
 /// defined by [`<sysexits.h>`].
 ///
+/// # Representation
+///
+/// `ExitCode` is guaranteed to have the same size and alignment as [`u8`], and
+/// the discriminant of each variant is the same as the corresponding value in
+/// [`<sysexits.h>`]. This representation is a stable part of the API, so a
+/// variant can be cast to [`u8`] with `as`.
+///
+/// Only `0` and `64..=78` are valid values of `ExitCode`, and any other value
+/// is undefined behavior. A value which comes in from an FFI boundary must
+/// therefore be received as [`u8`] and validated with [`TryFrom<u8>`](TryFrom)
+/// rather than being received as `ExitCode` directly.
+///
+/// ```
+/// # use sysexits::ExitCode;
+/// #
+/// assert_eq!(size_of::<ExitCode>(), 1);
+/// assert_eq!(align_of::<ExitCode>(), 1);
+/// assert_eq!(ExitCode::Usage as u8, 64);
+///
+/// // A value from FFI.
+/// let value: u8 = 65;
+/// assert_eq!(ExitCode::try_from(value), Ok(ExitCode::DataErr));
+/// assert!(ExitCode::try_from(1_u8).is_err());
+/// ```
+///
 /// [`<sysexits.h>`]: https://man.openbsd.org/sysexits
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(u8)]
 pub enum ExitCode {
     /// The successful exit.
     ///
@@ -252,195 +309,1548 @@ impl ExitCode {
         !self.is_success()
     }
 
-    /// Terminates the current process with the exit code defined by `ExitCode`.
+    /// Returns [`true`] if this system exit code represents a problem with the
+    /// operating system or the file system.
     ///
-    /// Equivalent to [`std::process::exit`] with a restricted exit code.
+    /// The following system exit codes are regarded as OS-related:
+    ///
+    /// - [`ExitCode::OsErr`]
+    /// - [`ExitCode::OsFile`]
+    /// - [`ExitCode::CantCreat`]
+    /// - [`ExitCode::IoErr`]
+    ///
+    /// These are the same as the system exit codes whose
+    /// [`category`](ExitCode::category) is [`Category::System`]. Such problems
+    /// usually cannot be fixed by the user, so they are worth reporting to an
+    /// operator.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sysexits::ExitCode;
     /// #
-    /// fn main() {
-    ///     ExitCode::Ok.exit();
-    /// }
+    /// assert!(ExitCode::IoErr.is_os_related());
+    /// assert!(!ExitCode::Usage.is_os_related());
     /// ```
-    #[cfg(feature = "std")]
+    ///
+    /// [`Category::System`]: crate::Category::System
+    #[must_use]
     #[inline]
-    pub fn exit(self) -> ! {
-        std::process::exit(self.into())
+    pub const fn is_os_related(&self) -> bool {
+        matches!(
+            self,
+            Self::OsErr | Self::OsFile | Self::CantCreat | Self::IoErr
+        )
     }
-}
 
-#[cfg(feature = "std")]
-impl std::error::Error for ExitCode {}
+    /// Returns [`true`] if this system exit code represents a failure caused by
+    /// the user.
+    ///
+    /// The following system exit codes are regarded as user errors:
+    ///
+    /// - [`ExitCode::Usage`]
+    /// - [`ExitCode::DataErr`]
+    /// - [`ExitCode::NoInput`]
+    /// - [`ExitCode::NoUser`]
+    /// - [`ExitCode::NoHost`]
+    /// - [`ExitCode::NoPerm`]
+    ///
+    /// These are the same as the system exit codes whose
+    /// [`category`](ExitCode::category) is [`Category::Usage`]. Such failures
+    /// can usually be fixed by the user, for example by correcting the
+    /// arguments, so it may be worth showing the usage help.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert!(ExitCode::Usage.is_user_error());
+    /// assert!(!ExitCode::IoErr.is_user_error());
+    /// ```
+    ///
+    /// [`Category::Usage`]: crate::Category::Usage
+    #[must_use]
+    #[inline]
+    pub const fn is_user_error(&self) -> bool {
+        matches!(
+            self,
+            Self::Usage
+                | Self::DataErr
+                | Self::NoInput
+                | Self::NoUser
+                | Self::NoHost
+                | Self::NoPerm
+        )
+    }
 
-#[cfg(feature = "std")]
-impl std::process::Termination for ExitCode {
+    /// Returns the suggested number of seconds to wait before retrying the
+    /// operation which resulted in this system exit code.
+    ///
+    /// Returns `Some(1)` for [`ExitCode::TempFail`], which indicates a
+    /// temporary failure where the user is invited to retry, and [`None`]
+    /// otherwise.
+    ///
+    /// This value is purely advisory. The caller is free to ignore it, or to
+    /// use it as the initial delay of its own backoff strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::TempFail.suggested_backoff_secs(), Some(1));
+    /// assert_eq!(ExitCode::Usage.suggested_backoff_secs(), None);
+    /// ```
+    #[must_use]
     #[inline]
-    fn report(self) -> std::process::ExitCode {
-        u8::from(self).into()
+    pub const fn suggested_backoff_secs(&self) -> Option<u32> {
+        match self {
+            Self::TempFail => Some(1),
+            _ => None,
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Returns the short description of this system exit code as written in
+    /// [`<sysexits.h>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.description(), "successful termination");
+    /// assert_eq!(ExitCode::Usage.description(), "command line usage error");
+    /// ```
+    ///
+    /// [`<sysexits.h>`]: https://man.openbsd.org/sysexits
+    #[must_use]
+    #[inline]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Ok => "successful termination",
+            Self::Usage => "command line usage error",
+            Self::DataErr => "data format error",
+            Self::NoInput => "cannot open input",
+            Self::NoUser => "addressee unknown",
+            Self::NoHost => "host name unknown",
+            Self::Unavailable => "service unavailable",
+            Self::Software => "internal software error",
+            Self::OsErr => "system error (e.g., can't fork)",
+            Self::OsFile => "critical OS file missing",
+            Self::CantCreat => "can't create (user) output file",
+            Self::IoErr => "input/output error",
+            Self::TempFail => "temp failure; user is invited to retry",
+            Self::Protocol => "remote error in protocol",
+            Self::NoPerm => "permission denied",
+            Self::Config => "configuration error",
+        }
+    }
 
-    #[test]
-    fn exit_code() {
-        assert_eq!(ExitCode::Ok as u8, 0);
-        assert_eq!(ExitCode::Usage as u8, 64);
-        assert_eq!(ExitCode::DataErr as u8, 65);
-        assert_eq!(ExitCode::NoInput as u8, 66);
-        assert_eq!(ExitCode::NoUser as u8, 67);
-        assert_eq!(ExitCode::NoHost as u8, 68);
-        assert_eq!(ExitCode::Unavailable as u8, 69);
-        assert_eq!(ExitCode::Software as u8, 70);
-        assert_eq!(ExitCode::OsErr as u8, 71);
-        assert_eq!(ExitCode::OsFile as u8, 72);
-        assert_eq!(ExitCode::CantCreat as u8, 73);
-        assert_eq!(ExitCode::IoErr as u8, 74);
-        assert_eq!(ExitCode::TempFail as u8, 75);
-        assert_eq!(ExitCode::Protocol as u8, 76);
-        assert_eq!(ExitCode::NoPerm as u8, 77);
-        assert_eq!(ExitCode::Config as u8, 78);
+    /// Returns the short description of the system exit code `code`.
+    ///
+    /// This is the same as [`ExitCode::description`], but takes the integer
+    /// representation directly. Returns [`None`] if `code` is not a valid
+    /// system exit code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(
+    ///     ExitCode::message_for_code(64),
+    ///     Some("command line usage error")
+    /// );
+    /// assert_eq!(ExitCode::message_for_code(1), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn message_for_code(code: u8) -> Option<&'static str> {
+        Self::try_from(code).ok().map(Self::description)
     }
 
-    #[test]
-    fn clone() {
-        assert_eq!(ExitCode::Ok.clone(), ExitCode::Ok);
+    /// Returns the integer representation of this system exit code as a
+    /// [`c_int`](core::ffi::c_int).
+    ///
+    /// This is useful when passing the exit code to C functions such as
+    /// [`exit(3)`]. The resulting value is `0` or `64..=78`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.as_c_int(), 0);
+    /// assert_eq!(ExitCode::Usage.as_c_int(), 64);
+    /// ```
+    ///
+    /// [`exit(3)`]: https://man.openbsd.org/exit.3
+    #[must_use]
+    #[inline]
+    pub const fn as_c_int(self) -> core::ffi::c_int {
+        self as core::ffi::c_int
     }
 
-    #[test]
-    fn copy() {
-        let a = ExitCode::Ok;
-        let b = a;
-        assert_eq!(a, b);
+    /// Converts this system exit code into a
+    /// [`NonZeroI32`](core::num::NonZeroI32).
+    ///
+    /// This is useful for C APIs which expect a nonzero `int` as an error.
+    /// Returns [`None`] if this is [`ExitCode::Ok`], since its integer
+    /// representation is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::num::NonZeroI32;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.into_nonzero_i32(), None);
+    /// assert_eq!(ExitCode::Usage.into_nonzero_i32(), NonZeroI32::new(64));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn into_nonzero_i32(self) -> Option<core::num::NonZeroI32> {
+        core::num::NonZeroI32::new(self as i32)
     }
 
-    #[test]
-    fn default() {
-        assert_eq!(ExitCode::default(), ExitCode::Ok);
+    /// Converts a [`NonZeroI32`](core::num::NonZeroI32) into an `ExitCode`.
+    ///
+    /// This is the inverse of [`ExitCode::into_nonzero_i32`]. Since `value` is
+    /// never `0`, the result is never [`ExitCode::Ok`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `value` is not in the range `64..=78`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::num::NonZeroI32;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let value = NonZeroI32::new(64).unwrap();
+    /// assert_eq!(ExitCode::try_from_nonzero_i32(value), Ok(ExitCode::Usage));
+    ///
+    /// let value = NonZeroI32::new(1).unwrap();
+    /// assert!(ExitCode::try_from_nonzero_i32(value).is_err());
+    /// ```
+    #[inline]
+    pub fn try_from_nonzero_i32(
+        value: core::num::NonZeroI32,
+    ) -> core::result::Result<Self, crate::error::ExitCodeRangeError> {
+        Self::try_from(value.get())
     }
 
-    #[test]
-    fn equality() {
-        assert_eq!(ExitCode::Ok, ExitCode::Ok);
-        assert_ne!(ExitCode::Ok, ExitCode::Usage);
-        assert_ne!(ExitCode::Ok, ExitCode::DataErr);
-        assert_ne!(ExitCode::Ok, ExitCode::NoInput);
-        assert_ne!(ExitCode::Ok, ExitCode::NoUser);
-        assert_ne!(ExitCode::Ok, ExitCode::NoHost);
-        assert_ne!(ExitCode::Ok, ExitCode::Unavailable);
-        assert_ne!(ExitCode::Ok, ExitCode::Software);
-        assert_ne!(ExitCode::Ok, ExitCode::OsErr);
-        assert_ne!(ExitCode::Ok, ExitCode::OsFile);
-        assert_ne!(ExitCode::Ok, ExitCode::CantCreat);
-        assert_ne!(ExitCode::Ok, ExitCode::IoErr);
-        assert_ne!(ExitCode::Ok, ExitCode::TempFail);
-        assert_ne!(ExitCode::Ok, ExitCode::Protocol);
-        assert_ne!(ExitCode::Ok, ExitCode::NoPerm);
-        assert_ne!(ExitCode::Ok, ExitCode::Config);
-        assert_ne!(ExitCode::Usage, ExitCode::Ok);
-        assert_eq!(ExitCode::Usage, ExitCode::Usage);
-        assert_ne!(ExitCode::Usage, ExitCode::DataErr);
-        assert_ne!(ExitCode::Usage, ExitCode::NoInput);
-        assert_ne!(ExitCode::Usage, ExitCode::NoUser);
-        assert_ne!(ExitCode::Usage, ExitCode::NoHost);
-        assert_ne!(ExitCode::Usage, ExitCode::Unavailable);
-        assert_ne!(ExitCode::Usage, ExitCode::Software);
-        assert_ne!(ExitCode::Usage, ExitCode::OsErr);
-        assert_ne!(ExitCode::Usage, ExitCode::OsFile);
-        assert_ne!(ExitCode::Usage, ExitCode::CantCreat);
-        assert_ne!(ExitCode::Usage, ExitCode::IoErr);
-        assert_ne!(ExitCode::Usage, ExitCode::TempFail);
-        assert_ne!(ExitCode::Usage, ExitCode::Protocol);
-        assert_ne!(ExitCode::Usage, ExitCode::NoPerm);
-        assert_ne!(ExitCode::Usage, ExitCode::Config);
-        assert_ne!(ExitCode::DataErr, ExitCode::Ok);
-        assert_ne!(ExitCode::DataErr, ExitCode::Usage);
-        assert_eq!(ExitCode::DataErr, ExitCode::DataErr);
-        assert_ne!(ExitCode::DataErr, ExitCode::NoInput);
-        assert_ne!(ExitCode::DataErr, ExitCode::NoUser);
-        assert_ne!(ExitCode::DataErr, ExitCode::NoHost);
-        assert_ne!(ExitCode::DataErr, ExitCode::Unavailable);
-        assert_ne!(ExitCode::DataErr, ExitCode::Software);
-        assert_ne!(ExitCode::DataErr, ExitCode::OsErr);
-        assert_ne!(ExitCode::DataErr, ExitCode::OsFile);
-        assert_ne!(ExitCode::DataErr, ExitCode::CantCreat);
-        assert_ne!(ExitCode::DataErr, ExitCode::IoErr);
-        assert_ne!(ExitCode::DataErr, ExitCode::TempFail);
-        assert_ne!(ExitCode::DataErr, ExitCode::Protocol);
-        assert_ne!(ExitCode::DataErr, ExitCode::NoPerm);
-        assert_ne!(ExitCode::DataErr, ExitCode::Config);
-        assert_ne!(ExitCode::NoInput, ExitCode::Ok);
-        assert_ne!(ExitCode::NoInput, ExitCode::Usage);
-        assert_ne!(ExitCode::NoInput, ExitCode::DataErr);
-        assert_eq!(ExitCode::NoInput, ExitCode::NoInput);
-        assert_ne!(ExitCode::NoInput, ExitCode::NoUser);
-        assert_ne!(ExitCode::NoInput, ExitCode::NoHost);
-        assert_ne!(ExitCode::NoInput, ExitCode::Unavailable);
-        assert_ne!(ExitCode::NoInput, ExitCode::Software);
-        assert_ne!(ExitCode::NoInput, ExitCode::OsErr);
-        assert_ne!(ExitCode::NoInput, ExitCode::OsFile);
-        assert_ne!(ExitCode::NoInput, ExitCode::CantCreat);
-        assert_ne!(ExitCode::NoInput, ExitCode::IoErr);
-        assert_ne!(ExitCode::NoInput, ExitCode::TempFail);
-        assert_ne!(ExitCode::NoInput, ExitCode::Protocol);
-        assert_ne!(ExitCode::NoInput, ExitCode::NoPerm);
-        assert_ne!(ExitCode::NoInput, ExitCode::Config);
-        assert_ne!(ExitCode::NoUser, ExitCode::Ok);
-        assert_ne!(ExitCode::NoUser, ExitCode::Usage);
-        assert_ne!(ExitCode::NoUser, ExitCode::DataErr);
-        assert_ne!(ExitCode::NoUser, ExitCode::NoInput);
-        assert_eq!(ExitCode::NoUser, ExitCode::NoUser);
-        assert_ne!(ExitCode::NoUser, ExitCode::NoHost);
-        assert_ne!(ExitCode::NoUser, ExitCode::Unavailable);
-        assert_ne!(ExitCode::NoUser, ExitCode::Software);
-        assert_ne!(ExitCode::NoUser, ExitCode::OsErr);
-        assert_ne!(ExitCode::NoUser, ExitCode::OsFile);
-        assert_ne!(ExitCode::NoUser, ExitCode::CantCreat);
-        assert_ne!(ExitCode::NoUser, ExitCode::IoErr);
-        assert_ne!(ExitCode::NoUser, ExitCode::TempFail);
-        assert_ne!(ExitCode::NoUser, ExitCode::Protocol);
-        assert_ne!(ExitCode::NoUser, ExitCode::NoPerm);
-        assert_ne!(ExitCode::NoUser, ExitCode::Config);
-        assert_ne!(ExitCode::NoHost, ExitCode::Ok);
-        assert_ne!(ExitCode::NoHost, ExitCode::Usage);
-        assert_ne!(ExitCode::NoHost, ExitCode::DataErr);
-        assert_ne!(ExitCode::NoHost, ExitCode::NoInput);
-        assert_ne!(ExitCode::NoHost, ExitCode::NoUser);
-        assert_eq!(ExitCode::NoHost, ExitCode::NoHost);
-        assert_ne!(ExitCode::NoHost, ExitCode::Unavailable);
-        assert_ne!(ExitCode::NoHost, ExitCode::Software);
-        assert_ne!(ExitCode::NoHost, ExitCode::OsErr);
-        assert_ne!(ExitCode::NoHost, ExitCode::OsFile);
-        assert_ne!(ExitCode::NoHost, ExitCode::CantCreat);
-        assert_ne!(ExitCode::NoHost, ExitCode::IoErr);
-        assert_ne!(ExitCode::NoHost, ExitCode::TempFail);
-        assert_ne!(ExitCode::NoHost, ExitCode::Protocol);
-        assert_ne!(ExitCode::NoHost, ExitCode::NoPerm);
-        assert_ne!(ExitCode::NoHost, ExitCode::Config);
-        assert_ne!(ExitCode::Unavailable, ExitCode::Ok);
-        assert_ne!(ExitCode::Unavailable, ExitCode::Usage);
-        assert_ne!(ExitCode::Unavailable, ExitCode::DataErr);
-        assert_ne!(ExitCode::Unavailable, ExitCode::NoInput);
-        assert_ne!(ExitCode::Unavailable, ExitCode::NoUser);
-        assert_ne!(ExitCode::Unavailable, ExitCode::NoHost);
-        assert_eq!(ExitCode::Unavailable, ExitCode::Unavailable);
-        assert_ne!(ExitCode::Unavailable, ExitCode::Software);
-        assert_ne!(ExitCode::Unavailable, ExitCode::OsErr);
-        assert_ne!(ExitCode::Unavailable, ExitCode::OsFile);
-        assert_ne!(ExitCode::Unavailable, ExitCode::CantCreat);
-        assert_ne!(ExitCode::Unavailable, ExitCode::IoErr);
-        assert_ne!(ExitCode::Unavailable, ExitCode::TempFail);
-        assert_ne!(ExitCode::Unavailable, ExitCode::Protocol);
-        assert_ne!(ExitCode::Unavailable, ExitCode::NoPerm);
-        assert_ne!(ExitCode::Unavailable, ExitCode::Config);
-        assert_ne!(ExitCode::Software, ExitCode::Ok);
-        assert_ne!(ExitCode::Software, ExitCode::Usage);
-        assert_ne!(ExitCode::Software, ExitCode::DataErr);
+    /// Returns the integer representation of this system exit code as the
+    /// native type of process exit codes on the current platform.
+    ///
+    /// On Unix, the type is [`i32`], the same as
+    /// [`ExitStatus::code`](std::process::ExitStatus::code) and
+    /// [`std::process::exit`]. This allows code which handles raw process exit
+    /// codes to be written without platform-specific casts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.as_raw(), 0);
+    /// assert_eq!(ExitCode::Usage.as_raw(), 64);
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    #[must_use]
+    #[inline]
+    pub const fn as_raw(self) -> i32 {
+        self as i32
+    }
+
+    /// Returns the integer representation of this system exit code as the
+    /// native type of process exit codes on the current platform.
+    ///
+    /// On Windows, the type is [`u32`], the same as the `DWORD` returned by
+    /// [`GetExitCodeProcess`]. This allows code which handles raw process exit
+    /// codes to be written without platform-specific casts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.as_raw(), 0);
+    /// assert_eq!(ExitCode::Usage.as_raw(), 64);
+    /// ```
+    ///
+    /// [`GetExitCodeProcess`]: https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getexitcodeprocess
+    #[cfg(all(feature = "std", windows))]
+    #[must_use]
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self as u32
+    }
+
+    /// Returns the integer representation of this system exit code.
+    ///
+    /// Unlike [`ExitCode::to_u8`], this takes `self` by reference, which is
+    /// convenient for comparisons and lookups through a reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.as_u8(), 0);
+    /// assert_eq!(ExitCode::Usage.as_u8(), 64);
+    ///
+    /// let codes = [ExitCode::DataErr, ExitCode::Usage];
+    /// let map: BTreeMap<_, _> = codes.iter().map(|code| (code.as_u8(), code)).collect();
+    /// assert_eq!(map.get(&64), Some(&&ExitCode::Usage));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the discriminant of this system exit code.
+    ///
+    /// The discriminant is the same as the integer representation, and each
+    /// value is guaranteed to be stable: it always matches the value defined
+    /// in [`<sysexits.h>`], so it can be relied on in const contexts and
+    /// across FFI boundaries.
+    ///
+    /// | Variant                   | Discriminant |
+    /// | ------------------------- | ------------ |
+    /// | [`ExitCode::Ok`]          | `0`          |
+    /// | [`ExitCode::Usage`]       | `64`         |
+    /// | [`ExitCode::DataErr`]     | `65`         |
+    /// | [`ExitCode::NoInput`]     | `66`         |
+    /// | [`ExitCode::NoUser`]      | `67`         |
+    /// | [`ExitCode::NoHost`]      | `68`         |
+    /// | [`ExitCode::Unavailable`] | `69`         |
+    /// | [`ExitCode::Software`]    | `70`         |
+    /// | [`ExitCode::OsErr`]       | `71`         |
+    /// | [`ExitCode::OsFile`]      | `72`         |
+    /// | [`ExitCode::CantCreat`]   | `73`         |
+    /// | [`ExitCode::IoErr`]       | `74`         |
+    /// | [`ExitCode::TempFail`]    | `75`         |
+    /// | [`ExitCode::Protocol`]    | `76`         |
+    /// | [`ExitCode::NoPerm`]      | `77`         |
+    /// | [`ExitCode::Config`]      | `78`         |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// const _: () = assert!(ExitCode::Usage.discriminant() == 64);
+    ///
+    /// assert_eq!(ExitCode::Ok.discriminant(), 0);
+    /// assert_eq!(ExitCode::Config.discriminant(), 78);
+    /// ```
+    ///
+    /// [`<sysexits.h>`]: https://man.openbsd.org/sysexits
+    #[must_use]
+    #[inline]
+    pub const fn discriminant(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the severity of this system exit code.
+    ///
+    /// Unlike the integer representation, the severity reflects how serious the
+    /// failure is. [`ExitCode::Ok`] has the lowest severity, and a greater
+    /// value means a more serious failure. The ranking is as follows:
+    ///
+    /// | Severity | Variant                                       |
+    /// | -------- | --------------------------------------------- |
+    /// | `0`      | [`ExitCode::Ok`]                              |
+    /// | `1`      | [`ExitCode::TempFail`]                        |
+    /// | `2`      | [`ExitCode::Usage`]                           |
+    /// | `3`      | [`ExitCode::DataErr`]                         |
+    /// | `4`      | [`ExitCode::NoInput`]                         |
+    /// | `5`      | [`ExitCode::NoUser`]                          |
+    /// | `6`      | [`ExitCode::NoHost`]                          |
+    /// | `7`      | [`ExitCode::NoPerm`]                          |
+    /// | `8`      | [`ExitCode::Config`]                          |
+    /// | `9`      | [`ExitCode::Unavailable`]                     |
+    /// | `10`     | [`ExitCode::Protocol`]                        |
+    /// | `11`     | [`ExitCode::CantCreat`]                       |
+    /// | `12`     | [`ExitCode::IoErr`]                           |
+    /// | `13`     | [`ExitCode::OsFile`]                          |
+    /// | `14`     | [`ExitCode::OsErr`]                           |
+    /// | `15`     | [`ExitCode::Software`]                        |
+    ///
+    /// A temporary failure is the least serious since the user is invited to
+    /// retry, followed by problems with the user's input, problems with the
+    /// environment, and finally problems with the operating system. An
+    /// internal software error is the most serious because it indicates a bug.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.severity(), 0);
+    /// assert!(ExitCode::Software.severity() > ExitCode::Config.severity());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn severity(self) -> u8 {
+        match self {
+            Self::Ok => 0,
+            Self::TempFail => 1,
+            Self::Usage => 2,
+            Self::DataErr => 3,
+            Self::NoInput => 4,
+            Self::NoUser => 5,
+            Self::NoHost => 6,
+            Self::NoPerm => 7,
+            Self::Config => 8,
+            Self::Unavailable => 9,
+            Self::Protocol => 10,
+            Self::CantCreat => 11,
+            Self::IoErr => 12,
+            Self::OsFile => 13,
+            Self::OsErr => 14,
+            Self::Software => 15,
+        }
+    }
+
+    /// Returns [`true`] if this system exit code is at least as severe as
+    /// `other`.
+    ///
+    /// This compares [`ExitCode::severity`], not the integer representation.
+    /// Since [`ExitCode::Ok`] has the lowest severity, every system exit code
+    /// is at least as severe as [`ExitCode::Ok`], and [`ExitCode::Ok`] is at
+    /// least as severe only as itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert!(ExitCode::Software.is_at_least_as_severe_as(ExitCode::Usage));
+    /// assert!(ExitCode::Usage.is_at_least_as_severe_as(ExitCode::Usage));
+    /// assert!(!ExitCode::Usage.is_at_least_as_severe_as(ExitCode::Software));
+    ///
+    /// assert!(ExitCode::Usage.is_at_least_as_severe_as(ExitCode::Ok));
+    /// assert!(!ExitCode::Ok.is_at_least_as_severe_as(ExitCode::TempFail));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_at_least_as_severe_as(self, other: Self) -> bool {
+        self.severity() >= other.severity()
+    }
+
+    /// Returns the next system exit code in ascending order of value.
+    ///
+    /// [`ExitCode::Usage`] follows [`ExitCode::Ok`]. Returns [`None`] if this
+    /// is [`ExitCode::Config`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.next(), Some(ExitCode::Usage));
+    /// assert_eq!(ExitCode::Usage.next(), Some(ExitCode::DataErr));
+    /// assert_eq!(ExitCode::Config.next(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn next(self) -> Option<Self> {
+        match self {
+            Self::Ok => Some(Self::Usage),
+            Self::Usage => Some(Self::DataErr),
+            Self::DataErr => Some(Self::NoInput),
+            Self::NoInput => Some(Self::NoUser),
+            Self::NoUser => Some(Self::NoHost),
+            Self::NoHost => Some(Self::Unavailable),
+            Self::Unavailable => Some(Self::Software),
+            Self::Software => Some(Self::OsErr),
+            Self::OsErr => Some(Self::OsFile),
+            Self::OsFile => Some(Self::CantCreat),
+            Self::CantCreat => Some(Self::IoErr),
+            Self::IoErr => Some(Self::TempFail),
+            Self::TempFail => Some(Self::Protocol),
+            Self::Protocol => Some(Self::NoPerm),
+            Self::NoPerm => Some(Self::Config),
+            Self::Config => None,
+        }
+    }
+
+    /// Returns the previous system exit code in ascending order of value.
+    ///
+    /// [`ExitCode::Ok`] precedes [`ExitCode::Usage`]. Returns [`None`] if this
+    /// is [`ExitCode::Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Config.prev(), Some(ExitCode::NoPerm));
+    /// assert_eq!(ExitCode::Usage.prev(), Some(ExitCode::Ok));
+    /// assert_eq!(ExitCode::Ok.prev(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn prev(self) -> Option<Self> {
+        match self {
+            Self::Ok => None,
+            Self::Usage => Some(Self::Ok),
+            Self::DataErr => Some(Self::Usage),
+            Self::NoInput => Some(Self::DataErr),
+            Self::NoUser => Some(Self::NoInput),
+            Self::NoHost => Some(Self::NoUser),
+            Self::Unavailable => Some(Self::NoHost),
+            Self::Software => Some(Self::Unavailable),
+            Self::OsErr => Some(Self::Software),
+            Self::OsFile => Some(Self::OsErr),
+            Self::CantCreat => Some(Self::OsFile),
+            Self::IoErr => Some(Self::CantCreat),
+            Self::TempFail => Some(Self::IoErr),
+            Self::Protocol => Some(Self::TempFail),
+            Self::NoPerm => Some(Self::Protocol),
+            Self::Config => Some(Self::NoPerm),
+        }
+    }
+
+    /// Converts a [`bool`] into an `ExitCode`.
+    ///
+    /// Returns [`ExitCode::Ok`] if `ok` is [`true`], and `on_false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(
+    ///     ExitCode::from_bool_with(true, ExitCode::DataErr),
+    ///     ExitCode::Ok
+    /// );
+    /// assert_eq!(
+    ///     ExitCode::from_bool_with(false, ExitCode::DataErr),
+    ///     ExitCode::DataErr
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_bool_with(ok: bool, on_false: Self) -> Self {
+        if ok {
+            Self::Ok
+        } else {
+            on_false
+        }
+    }
+
+    /// Converts a three-state outcome into an `ExitCode`.
+    ///
+    /// This is useful for programs like [`grep(1)`] which distinguish between
+    /// a match, no match, and an error. The outcome is converted as follows:
+    ///
+    /// - If `error` is [`Some`], returns the contained `ExitCode` regardless of
+    ///   `matched`.
+    /// - Otherwise, returns [`ExitCode::Ok`] if `matched` is [`true`], and
+    ///   `no_match` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(
+    ///     ExitCode::from_outcome(true, ExitCode::DataErr, None),
+    ///     ExitCode::Ok
+    /// );
+    /// assert_eq!(
+    ///     ExitCode::from_outcome(false, ExitCode::DataErr, None),
+    ///     ExitCode::DataErr
+    /// );
+    /// assert_eq!(
+    ///     ExitCode::from_outcome(false, ExitCode::DataErr, Some(ExitCode::NoInput)),
+    ///     ExitCode::NoInput
+    /// );
+    /// ```
+    ///
+    /// [`grep(1)`]: https://man.openbsd.org/grep.1
+    #[must_use]
+    #[inline]
+    pub const fn from_outcome(matched: bool, no_match: Self, error: Option<Self>) -> Self {
+        match error {
+            Some(code) => code,
+            None => Self::from_bool_with(matched, no_match),
+        }
+    }
+
+    /// Parses an ASCII decimal number `bytes` into an `ExitCode`.
+    ///
+    /// `bytes` must consist only of ASCII digits, and the number must be `0`
+    /// or `64..=78`. Leading zeros are allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `bytes` is empty, contains a byte which is not an
+    /// ASCII digit, or is not a valid exit code number. The cause can be
+    /// obtained with [`ParseExitCodeError::kind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::try_from_ascii(b"0"), Ok(ExitCode::Ok));
+    /// assert_eq!(ExitCode::try_from_ascii(b"64"), Ok(ExitCode::Usage));
+    ///
+    /// assert!(ExitCode::try_from_ascii(b"79").is_err());
+    /// assert!(ExitCode::try_from_ascii(b"Usage").is_err());
+    /// ```
+    #[inline]
+    pub const fn try_from_ascii(bytes: &[u8]) -> Result<Self, ParseExitCodeError> {
+        if bytes.is_empty() {
+            return Err(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber));
+        }
+        let mut value: u8 = 0;
+        let mut overflow = false;
+        let mut i = 0;
+        while i < bytes.len() {
+            let digit = bytes[i].wrapping_sub(b'0');
+            if digit > 9 {
+                return Err(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber));
+            }
+            match value.checked_mul(10) {
+                Some(v) => match v.checked_add(digit) {
+                    Some(v) => value = v,
+                    None => overflow = true,
+                },
+                None => overflow = true,
+            }
+            i += 1;
+        }
+        if overflow {
+            return Err(ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange));
+        }
+        match Self::from_u8_opt(value) {
+            Some(code) => Ok(code),
+            None => Err(ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange)),
+        }
+    }
+
+    /// Returns the system exit code whose integer representation is `value`,
+    /// or [`None`] if `value` is not `0` or `64..=78`.
+    ///
+    /// This is the table which the `const` conversions from integers share.
+    #[inline]
+    const fn from_u8_opt(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Ok),
+            64 => Some(Self::Usage),
+            65 => Some(Self::DataErr),
+            66 => Some(Self::NoInput),
+            67 => Some(Self::NoUser),
+            68 => Some(Self::NoHost),
+            69 => Some(Self::Unavailable),
+            70 => Some(Self::Software),
+            71 => Some(Self::OsErr),
+            72 => Some(Self::OsFile),
+            73 => Some(Self::CantCreat),
+            74 => Some(Self::IoErr),
+            75 => Some(Self::TempFail),
+            76 => Some(Self::Protocol),
+            77 => Some(Self::NoPerm),
+            78 => Some(Self::Config),
+            _ => None,
+        }
+    }
+
+    /// Converts a shell-style exit status (e.g., the value of `$?`) into an
+    /// `ExitCode`.
+    ///
+    /// Shells report a process terminated by a signal as `128` plus the signal
+    /// number, so the conversion uses the following heuristic:
+    ///
+    /// - `0` and `64..=78` are converted into the corresponding variant.
+    /// - `128` or greater is regarded as termination by a signal, and is
+    ///   converted into [`ExitCode::OsErr`].
+    /// - Any other value is converted into [`ExitCode::Software`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::from_shell_status(0), ExitCode::Ok);
+    /// assert_eq!(ExitCode::from_shell_status(65), ExitCode::DataErr);
+    /// assert_eq!(ExitCode::from_shell_status(130), ExitCode::OsErr);
+    /// assert_eq!(ExitCode::from_shell_status(1), ExitCode::Software);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn from_shell_status(code: i32) -> Self {
+        match Self::try_from(code) {
+            Ok(code) => code,
+            Err(_) if code >= 128 => Self::OsErr,
+            Err(_) => Self::Software,
+        }
+    }
+
+    /// Converts the number of a signal which terminated a process into an
+    /// `ExitCode`.
+    ///
+    /// The signals are converted as follows:
+    ///
+    /// | Signal    | `ExitCode`               |
+    /// | --------- | ------------------------ |
+    /// | `SIGHUP`  | [`ExitCode::TempFail`]   |
+    /// | `SIGTERM` | [`ExitCode::TempFail`]   |
+    /// | Others    | [`ExitCode::OsErr`]      |
+    ///
+    /// `SIGHUP` and `SIGTERM` usually request a graceful termination (e.g., the
+    /// terminal was closed or the system is shutting down), so the operation
+    /// may succeed if retried later. Any other signal is regarded as an
+    /// operating system error.
+    ///
+    /// The signal number can be obtained with
+    /// [`ExitStatusExt::signal`](std::os::unix::process::ExitStatusExt::signal).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// // SIGTERM
+    /// assert_eq!(ExitCode::from_signal(15), ExitCode::TempFail);
+    /// // SIGKILL
+    /// assert_eq!(ExitCode::from_signal(9), ExitCode::OsErr);
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    #[must_use]
+    #[inline]
+    pub const fn from_signal(signum: i32) -> Self {
+        const SIGHUP: i32 = 1;
+        const SIGTERM: i32 = 15;
+
+        match signum {
+            SIGHUP | SIGTERM => Self::TempFail,
+            _ => Self::OsErr,
+        }
+    }
+
+    /// Converts an arbitrary process exit code into an `ExitCode`.
+    ///
+    /// This is useful for wrapping programs whose exit codes are not
+    /// controlled. The conversion never fails, and uses the following rules:
+    ///
+    /// - `0` and `64..=78` are converted into the corresponding variant.
+    /// - A negative value is converted into [`ExitCode::OsErr`], because it
+    ///   usually indicates an abnormal termination reported by the operating
+    ///   system (e.g., an `NTSTATUS` on Windows).
+    /// - Any other value is regarded as a generic failure, and is converted
+    ///   into [`ExitCode::Software`].
+    ///
+    /// Unlike [`ExitCode::from_shell_status`], `128` or greater is not regarded
+    /// as termination by a signal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::clamp_from_i32(0), ExitCode::Ok);
+    /// assert_eq!(ExitCode::clamp_from_i32(65), ExitCode::DataErr);
+    /// assert_eq!(ExitCode::clamp_from_i32(1), ExitCode::Software);
+    /// assert_eq!(ExitCode::clamp_from_i32(255), ExitCode::Software);
+    /// assert_eq!(ExitCode::clamp_from_i32(-1), ExitCode::OsErr);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn clamp_from_i32(code: i32) -> Self {
+        match Self::try_from(code) {
+            Ok(code) => code,
+            Err(_) if code.is_negative() => Self::OsErr,
+            Err(_) => Self::Software,
+        }
+    }
+
+    /// Converts an arbitrary process exit code into an `ExitCode` by
+    /// saturating it to the range of the system exit codes.
+    ///
+    /// The conversion never fails, and uses the following rules:
+    ///
+    /// - `0` and `64..=78` are converted into the corresponding variant.
+    /// - A value greater than `78` is converted into [`ExitCode::MAX`].
+    /// - Any other value (i.e., a negative value or `1..=63`) is converted into
+    ///   [`ExitCode::BASE`]. Note that only `0` is converted into
+    ///   [`ExitCode::Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::saturating_from_i32(0), ExitCode::Ok);
+    /// assert_eq!(ExitCode::saturating_from_i32(65), ExitCode::DataErr);
+    /// assert_eq!(ExitCode::saturating_from_i32(255), ExitCode::MAX);
+    /// assert_eq!(ExitCode::saturating_from_i32(1), ExitCode::BASE);
+    /// assert_eq!(ExitCode::saturating_from_i32(-1), ExitCode::BASE);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn saturating_from_i32(code: i32) -> Self {
+        match code {
+            i32::MIN..=-1 => Self::BASE,
+            79..=i32::MAX => Self::MAX,
+            // `code` is `0..=78` here, so the cast is lossless.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            _ => match Self::from_u8_opt(code as u8) {
+                Some(code) => code,
+                None => Self::BASE,
+            },
+        }
+    }
+
+    /// Converts an [`u8`] into an `ExitCode`, falling back to
+    /// [`ExitCode::Software`].
+    ///
+    /// The conversion never fails, and uses the following rules:
+    ///
+    /// - `0` and `64..=78` are converted into the corresponding variant.
+    /// - Any other value is converted into [`ExitCode::Software`], since it
+    ///   indicates that the program terminated for a reason which is not
+    ///   described by the system exit codes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::from_u8_lossy(0), ExitCode::Ok);
+    /// assert_eq!(ExitCode::from_u8_lossy(65), ExitCode::DataErr);
+    /// assert_eq!(ExitCode::from_u8_lossy(1), ExitCode::Software);
+    /// assert_eq!(ExitCode::from_u8_lossy(255), ExitCode::Software);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_u8_lossy(value: u8) -> Self {
+        match Self::from_u8_opt(value) {
+            Some(code) => code,
+            None => Self::Software,
+        }
+    }
+
+    /// Asserts that `code` is a valid system exit code.
+    ///
+    /// This is intended to be evaluated in a constant context, where an invalid
+    /// `code` results in a compile error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `code` is not `0` or `64..=78`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// const _: () = ExitCode::assert_valid_code(0);
+    /// const _: () = ExitCode::assert_valid_code(64);
+    /// ```
+    ///
+    /// An invalid value fails to compile:
+    ///
+    /// ```compile_fail
+    /// # use sysexits::ExitCode;
+    /// #
+    /// const _: () = ExitCode::assert_valid_code(79);
+    /// ```
+    #[inline]
+    pub const fn assert_valid_code(code: u8) {
+        assert!(
+            matches!(code, 0 | 64..=78),
+            "exit code must be `0` or in the range `64..=78`"
+        );
+    }
+
+    /// Converts a [`Utf8Error`](core::str::Utf8Error) into an `ExitCode`,
+    /// preserving the position where decoding failed.
+    ///
+    /// The `ExitCode` is always [`ExitCode::DataErr`], and the [`usize`] is the
+    /// index in the input up to which valid UTF-8 was verified (see
+    /// [`Utf8Error::valid_up_to`](core::str::Utf8Error::valid_up_to)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::str;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let bytes = [b'f', b'o', b'o', 0xff];
+    /// let err = str::from_utf8(&bytes).unwrap_err();
+    /// assert_eq!(ExitCode::from_utf8_error(&err), (ExitCode::DataErr, 3));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_utf8_error(err: &core::str::Utf8Error) -> (Self, usize) {
+        (Self::DataErr, err.valid_up_to())
+    }
+
+    /// Converts an [`io::Error`](std::io::Error) which occurred while creating
+    /// or writing an output into an `ExitCode`.
+    ///
+    /// The conversion from [`io::Error`](std::io::Error) assumes that the error
+    /// occurred while reading an input, so [`ErrorKind::NotFound`] is
+    /// converted into [`ExitCode::NoInput`]. Use this function instead when the
+    /// error occurred while creating or writing an output file, where a missing
+    /// parent directory means that the output cannot be created.
+    ///
+    /// The following kinds are converted with output-oriented semantics, and
+    /// the others are converted in the same way as the conversion from
+    /// [`io::Error`](std::io::Error):
+    ///
+    /// - [`ErrorKind::NotFound`] is converted into [`ExitCode::CantCreat`].
+    /// - [`ErrorKind::PermissionDenied`] is converted into
+    ///   [`ExitCode::NoPerm`].
+    /// - [`ErrorKind::AlreadyExists`] is converted into
+    ///   [`ExitCode::CantCreat`].
+    /// - If the `extended_io_error` feature is enabled,
+    ///   [`ErrorKind::StorageFull`] is converted into [`ExitCode::CantCreat`].
+    ///
+    /// The conversion from [`io::Error`](std::io::Error) converts
+    /// [`ErrorKind::StorageFull`] into [`ExitCode::IoErr`], since running out
+    /// of disk space is a generic I/O failure from the point of view of a
+    /// reader. For a writer, however, it means that the output could not be
+    /// created in full, so this function reports it as
+    /// [`ExitCode::CantCreat`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{Error, ErrorKind};
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let err = Error::from(ErrorKind::NotFound);
+    /// assert_eq!(
+    ///     ExitCode::from_io_error_for_output(&err),
+    ///     ExitCode::CantCreat
+    /// );
+    /// assert_eq!(ExitCode::from(err), ExitCode::NoInput);
+    /// ```
+    ///
+    /// [`ErrorKind::NotFound`]: std::io::ErrorKind::NotFound
+    /// [`ErrorKind::PermissionDenied`]: std::io::ErrorKind::PermissionDenied
+    /// [`ErrorKind::AlreadyExists`]: std::io::ErrorKind::AlreadyExists
+    /// [`ErrorKind::StorageFull`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.StorageFull
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn from_io_error_for_output(err: &std::io::Error) -> Self {
+        use std::io::ErrorKind;
+
+        match err.kind() {
+            ErrorKind::NotFound | ErrorKind::AlreadyExists => Self::CantCreat,
+            ErrorKind::PermissionDenied => Self::NoPerm,
+            #[cfg(feature = "extended_io_error")]
+            ErrorKind::StorageFull => Self::CantCreat,
+            kind => kind.into(),
+        }
+    }
+
+    /// Executes `cmd` as a child process, waits for it to finish, and converts
+    /// its exit status into an `ExitCode`.
+    ///
+    /// If the exit code of the child process is a system exit code, it is
+    /// returned as is. Any other exit code (including one greater than or
+    /// equal to 128) is converted into [`ExitCode::Software`]. If the child
+    /// process was terminated by a signal, this returns [`ExitCode::OsErr`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the child process could not be executed. See
+    /// [`Command::status`](std::process::Command::status).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::process::Command;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// # #[cfg(unix)]
+    /// # {
+    /// let code = ExitCode::from_command(Command::new("sh").args(["-c", "exit 65"]));
+    /// assert_eq!(code.unwrap(), ExitCode::DataErr);
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_command(cmd: &mut std::process::Command) -> std::io::Result<Self> {
+        cmd.status().map(Self::from_exit_status_lenient)
+    }
+
+    /// Converts `status` leniently: an exit code which is not a system exit
+    /// code becomes [`ExitCode::Software`], and termination by a signal
+    /// becomes [`ExitCode::OsErr`].
+    ///
+    /// Unlike [`ExitCode::from_shell_status`], this does not regard an exit
+    /// code greater than or equal to 128 as termination by a signal, since
+    /// [`ExitStatus::code`](std::process::ExitStatus::code) already returns
+    /// [`None`] in that case.
+    #[cfg(feature = "std")]
+    fn from_exit_status_lenient(status: std::process::ExitStatus) -> Self {
+        status.code().map_or(Self::OsErr, |code| {
+            Self::try_from(code).unwrap_or(Self::Software)
+        })
+    }
+
+    /// Converts the exit status of a finished child process into an
+    /// `ExitCode`.
+    ///
+    /// The exit status is converted leniently in the same way as
+    /// [`ExitCode::from_command`], so this never fails. Unlike the
+    /// [`TryFrom<ExitStatus>`](std::process::ExitStatus) implementation, an
+    /// exit code which is not a system exit code (including one greater than
+    /// or equal to 128) is converted into [`ExitCode::Software`], and
+    /// termination by a signal is converted into [`ExitCode::OsErr`]. Use
+    /// [`ExitCode::from_output_strict`] to reject them instead.
+    ///
+    /// The captured standard output and standard error are left untouched, so
+    /// they can be inspected afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::process::Command;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// # #[cfg(unix)]
+    /// # {
+    /// let output = Command::new("sh")
+    ///     .args(["-c", "echo 'invalid input' >&2; exit 65"])
+    ///     .output()
+    ///     .unwrap();
+    /// assert_eq!(ExitCode::from_output(&output), ExitCode::DataErr);
+    /// assert_eq!(output.stderr, b"invalid input\n");
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn from_output(output: &std::process::Output) -> Self {
+        Self::from_exit_status_lenient(output.status)
+    }
+
+    /// Converts the exit status of a finished child process into an
+    /// `ExitCode`, rejecting anything which is not a system exit code.
+    ///
+    /// This is equivalent to the
+    /// [`TryFrom<ExitStatus>`](std::process::ExitStatus) implementation
+    /// applied to [`Output::status`](std::process::Output::status).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the exit code of the child process is not a system
+    /// exit code, or if the child process was terminated by a signal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::process::Command;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// # #[cfg(unix)]
+    /// # {
+    /// let output = Command::new("sh").args(["-c", "exit 65"]).output().unwrap();
+    /// assert_eq!(ExitCode::from_output_strict(&output), Ok(ExitCode::DataErr));
+    ///
+    /// let output = Command::new("sh").args(["-c", "exit 1"]).output().unwrap();
+    /// assert!(ExitCode::from_output_strict(&output).is_err());
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_output_strict(
+        output: &std::process::Output,
+    ) -> core::result::Result<Self, crate::error::TryFromExitStatusError> {
+        Self::try_from(output.status)
+    }
+
+    /// Reads the environment variable `var` and parses its value into an
+    /// `ExitCode`.
+    ///
+    /// The value is parsed in the same way as the conversion from
+    /// [`&str`](str), so both a number (e.g., `64`) and a name (e.g., `Usage`
+    /// or `EX_USAGE`) are accepted.
+    ///
+    /// This is intended for testing and scripting scenarios, such as letting
+    /// the user override the exit code of a program.
+    ///
+    /// Returns [`None`] if `var` is not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Some(Err(_))` if the value of `var` cannot be parsed into an
+    /// `ExitCode`. A value which is not valid Unicode is never valid, and its
+    /// error kind is chosen in the same way as the conversion from
+    /// [`&str`](str): [`NotANumber`](ParseExitCodeErrorKind::NotANumber) if it
+    /// starts with an ASCII digit, `+` or `-`, and
+    /// [`UnknownName`](ParseExitCodeErrorKind::UnknownName) otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::from_env("SYSEXITS_DOC_UNSET"), None);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn from_env(var: &str) -> Option<core::result::Result<Self, ParseExitCodeError>> {
+        let value = std::env::var_os(var)?;
+        Some(value.to_str().map_or_else(
+            || match value.as_encoded_bytes().first() {
+                Some(b'0'..=b'9' | b'+' | b'-') => {
+                    Err(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber))
+                }
+                _ => Err(ParseExitCodeError::new(ParseExitCodeErrorKind::UnknownName)),
+            },
+            Self::try_from,
+        ))
+    }
+
+    /// Creates an [`ExitStatus`](std::process::ExitStatus) which has this
+    /// system exit code as its exit code.
+    ///
+    /// This uses the platform-specific extension traits
+    /// ([`std::os::unix::process::ExitStatusExt`] on Unix and
+    /// `std::os::windows::process::ExitStatusExt` on Windows), so no child
+    /// process is executed. This is useful for testing code which handles the
+    /// exit status of a child process.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let status = ExitCode::DataErr.to_exit_status();
+    /// assert_eq!(status.code(), Some(65));
+    /// assert_eq!(ExitCode::try_from(status), Ok(ExitCode::DataErr));
+    /// ```
+    #[cfg(all(feature = "std", any(unix, windows)))]
+    #[must_use]
+    #[inline]
+    pub fn to_exit_status(self) -> std::process::ExitStatus {
+        #[cfg(unix)]
+        use std::os::unix::process::ExitStatusExt;
+        #[cfg(windows)]
+        use std::os::windows::process::ExitStatusExt;
+
+        // On Unix, the raw value is a wait status, where the exit code is
+        // stored in the second least significant byte.
+        #[cfg(unix)]
+        let raw = self.as_raw() << 8;
+        #[cfg(windows)]
+        let raw = self.as_raw();
+        std::process::ExitStatus::from_raw(raw)
+    }
+
+    /// Terminates the current process with the exit code defined by `ExitCode`.
+    ///
+    /// Equivalent to [`std::process::exit`] with a restricted exit code.
+    ///
+    /// The exit code is computed directly from the integer representation of
+    /// `ExitCode`, so this method never panics before terminating the process.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// fn main() {
+    ///     ExitCode::Ok.exit();
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    #[track_caller]
+    pub fn exit(self) -> ! {
+        std::process::exit(self.to_i32())
+    }
+
+    /// Terminates the current process according to `result`.
+    ///
+    /// If `result` is [`Ok`], this terminates the current process with
+    /// [`ExitCode::Ok`]. If `result` is [`Err`], this prints the error to the
+    /// standard error and terminates the current process with the `ExitCode`
+    /// converted from the error.
+    ///
+    /// This function never returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// fn main() {
+    ///     let result = "42".parse::<u8>().map_err(|_| ExitCode::DataErr);
+    ///     ExitCode::exit_from_result(result);
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    #[track_caller]
+    pub fn exit_from_result<T, E>(result: core::result::Result<T, E>) -> !
+    where
+        E: core::fmt::Display + Into<Self>,
+    {
+        match result {
+            Ok(_) => Self::Ok.exit(),
+            Err(err) => {
+                std::eprintln!("Error: {err}");
+                err.into().exit()
+            }
+        }
+    }
+
+    /// Runs `f` and converts its result into a
+    /// [`std::process::ExitCode`].
+    ///
+    /// If `f` returns [`Ok`], this returns the process exit code of
+    /// [`ExitCode::Ok`]. If `f` returns [`Err`], this prints the error to the
+    /// standard error and returns the process exit code of the `ExitCode`
+    /// converted from the error.
+    ///
+    /// Unlike [`ExitCode::exit_from_result`], this does not terminate the
+    /// current process, so destructors are run when returning from `main`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// fn main() -> std::process::ExitCode {
+    ///     ExitCode::run(|| {
+    ///         let n = "42".parse::<u8>().map_err(|_| ExitCode::DataErr)?;
+    ///         println!("{n}");
+    ///         Ok::<_, ExitCode>(())
+    ///     })
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn run<F, E>(f: F) -> std::process::ExitCode
+    where
+        F: FnOnce() -> core::result::Result<(), E>,
+        E: Into<Self> + core::fmt::Display,
+    {
+        match f() {
+            Ok(()) => Self::Ok.into(),
+            Err(err) => {
+                std::eprintln!("Error: {err}");
+                err.into().into()
+            }
+        }
+    }
+
+    /// Registers a panic hook which terminates the current process with this
+    /// `ExitCode`.
+    ///
+    /// When a panic occurs, the hook prints the panic message to the standard
+    /// error and then calls [`ExitCode::exit`], instead of unwinding or
+    /// aborting.
+    ///
+    /// The panic hook is global to the process, so a panic in any thread will
+    /// terminate the whole process, and this replaces any previously registered
+    /// panic hook (including the default one).
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from a panicking thread.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use sysexits::ExitCode;
+    /// #
+    /// ExitCode::Software.set_panic_exit_code();
+    ///
+    /// // The process exits with `70`.
+    /// panic!("something went wrong");
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn set_panic_exit_code(self) {
+        std::panic::set_hook(std::boxed::Box::new(move |info| {
+            std::eprintln!("{info}");
+            self.exit();
+        }));
+    }
+
+    /// Converts this `ExitCode` into an [`std::process::ExitCode`].
+    ///
+    /// This is equivalent to calling
+    /// [`Termination::report`](std::process::Termination::report), but does not
+    /// require the trait to be in scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(
+    ///     format!("{:?}", ExitCode::Usage.as_process_exit_code()),
+    ///     format!("{:?}", std::process::ExitCode::from(64))
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn as_process_exit_code(self) -> std::process::ExitCode {
+        use std::process::Termination;
+
+        self.report()
+    }
+
+    /// Writes `message` followed by a newline into `err` if it is [`Some`],
+    /// and converts this `ExitCode` into an [`std::process::ExitCode`].
+    ///
+    /// Unlike [`ExitCode::exit_from_result`], this does not terminate the
+    /// current process, so the written message can be inspected by passing a
+    /// buffer such as [`Vec<u8>`](std::vec::Vec) as `err`. Pass
+    /// [`std::io::stderr`] to print the message to the standard error.
+    ///
+    /// Any error while writing the message is ignored, since the process exit
+    /// code should be reported regardless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let mut buf = Vec::new();
+    /// let code = ExitCode::NoInput.report_to(&mut buf, Some("Error: no such file"));
+    /// assert_eq!(buf, b"Error: no such file\n");
+    /// assert_eq!(
+    ///     format!("{code:?}"),
+    ///     format!("{:?}", std::process::ExitCode::from(66))
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn report_to<W: std::io::Write>(
+        self,
+        err: &mut W,
+        message: Option<&str>,
+    ) -> std::process::ExitCode {
+        if let Some(message) = message {
+            let _ = writeln!(err, "{message}");
+        }
+        self.as_process_exit_code()
+    }
+}
+
+impl core::error::Error for ExitCode {}
+
+#[cfg(feature = "std")]
+impl std::process::Termination for ExitCode {
+    #[inline]
+    fn report(self) -> std::process::ExitCode {
+        u8::from(self).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{category::Category, *};
+
+    #[test]
+    fn exit_code() {
+        assert_eq!(ExitCode::Ok as u8, 0);
+        assert_eq!(ExitCode::Usage as u8, 64);
+        assert_eq!(ExitCode::DataErr as u8, 65);
+        assert_eq!(ExitCode::NoInput as u8, 66);
+        assert_eq!(ExitCode::NoUser as u8, 67);
+        assert_eq!(ExitCode::NoHost as u8, 68);
+        assert_eq!(ExitCode::Unavailable as u8, 69);
+        assert_eq!(ExitCode::Software as u8, 70);
+        assert_eq!(ExitCode::OsErr as u8, 71);
+        assert_eq!(ExitCode::OsFile as u8, 72);
+        assert_eq!(ExitCode::CantCreat as u8, 73);
+        assert_eq!(ExitCode::IoErr as u8, 74);
+        assert_eq!(ExitCode::TempFail as u8, 75);
+        assert_eq!(ExitCode::Protocol as u8, 76);
+        assert_eq!(ExitCode::NoPerm as u8, 77);
+        assert_eq!(ExitCode::Config as u8, 78);
+    }
+
+    #[test]
+    fn size_of() {
+        assert_eq!(core::mem::size_of::<ExitCode>(), 1);
+        assert_eq!(core::mem::size_of::<ExitCode>(), core::mem::size_of::<u8>());
+    }
+
+    #[test]
+    fn align_of() {
+        assert_eq!(
+            core::mem::align_of::<ExitCode>(),
+            core::mem::align_of::<u8>()
+        );
+    }
+
+    #[test]
+    fn clone() {
+        assert_eq!(ExitCode::Ok.clone(), ExitCode::Ok);
+    }
+
+    #[test]
+    fn copy() {
+        let a = ExitCode::Ok;
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(ExitCode::default(), ExitCode::Ok);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let set: HashSet<_> = ExitCode::iter().chain(ExitCode::iter()).collect();
+        assert_eq!(set.len(), 16);
+        assert!(ExitCode::iter().all(|code| set.contains(&code)));
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(ExitCode::Ok, ExitCode::Ok);
+        assert_ne!(ExitCode::Ok, ExitCode::Usage);
+        assert_ne!(ExitCode::Ok, ExitCode::DataErr);
+        assert_ne!(ExitCode::Ok, ExitCode::NoInput);
+        assert_ne!(ExitCode::Ok, ExitCode::NoUser);
+        assert_ne!(ExitCode::Ok, ExitCode::NoHost);
+        assert_ne!(ExitCode::Ok, ExitCode::Unavailable);
+        assert_ne!(ExitCode::Ok, ExitCode::Software);
+        assert_ne!(ExitCode::Ok, ExitCode::OsErr);
+        assert_ne!(ExitCode::Ok, ExitCode::OsFile);
+        assert_ne!(ExitCode::Ok, ExitCode::CantCreat);
+        assert_ne!(ExitCode::Ok, ExitCode::IoErr);
+        assert_ne!(ExitCode::Ok, ExitCode::TempFail);
+        assert_ne!(ExitCode::Ok, ExitCode::Protocol);
+        assert_ne!(ExitCode::Ok, ExitCode::NoPerm);
+        assert_ne!(ExitCode::Ok, ExitCode::Config);
+        assert_ne!(ExitCode::Usage, ExitCode::Ok);
+        assert_eq!(ExitCode::Usage, ExitCode::Usage);
+        assert_ne!(ExitCode::Usage, ExitCode::DataErr);
+        assert_ne!(ExitCode::Usage, ExitCode::NoInput);
+        assert_ne!(ExitCode::Usage, ExitCode::NoUser);
+        assert_ne!(ExitCode::Usage, ExitCode::NoHost);
+        assert_ne!(ExitCode::Usage, ExitCode::Unavailable);
+        assert_ne!(ExitCode::Usage, ExitCode::Software);
+        assert_ne!(ExitCode::Usage, ExitCode::OsErr);
+        assert_ne!(ExitCode::Usage, ExitCode::OsFile);
+        assert_ne!(ExitCode::Usage, ExitCode::CantCreat);
+        assert_ne!(ExitCode::Usage, ExitCode::IoErr);
+        assert_ne!(ExitCode::Usage, ExitCode::TempFail);
+        assert_ne!(ExitCode::Usage, ExitCode::Protocol);
+        assert_ne!(ExitCode::Usage, ExitCode::NoPerm);
+        assert_ne!(ExitCode::Usage, ExitCode::Config);
+        assert_ne!(ExitCode::DataErr, ExitCode::Ok);
+        assert_ne!(ExitCode::DataErr, ExitCode::Usage);
+        assert_eq!(ExitCode::DataErr, ExitCode::DataErr);
+        assert_ne!(ExitCode::DataErr, ExitCode::NoInput);
+        assert_ne!(ExitCode::DataErr, ExitCode::NoUser);
+        assert_ne!(ExitCode::DataErr, ExitCode::NoHost);
+        assert_ne!(ExitCode::DataErr, ExitCode::Unavailable);
+        assert_ne!(ExitCode::DataErr, ExitCode::Software);
+        assert_ne!(ExitCode::DataErr, ExitCode::OsErr);
+        assert_ne!(ExitCode::DataErr, ExitCode::OsFile);
+        assert_ne!(ExitCode::DataErr, ExitCode::CantCreat);
+        assert_ne!(ExitCode::DataErr, ExitCode::IoErr);
+        assert_ne!(ExitCode::DataErr, ExitCode::TempFail);
+        assert_ne!(ExitCode::DataErr, ExitCode::Protocol);
+        assert_ne!(ExitCode::DataErr, ExitCode::NoPerm);
+        assert_ne!(ExitCode::DataErr, ExitCode::Config);
+        assert_ne!(ExitCode::NoInput, ExitCode::Ok);
+        assert_ne!(ExitCode::NoInput, ExitCode::Usage);
+        assert_ne!(ExitCode::NoInput, ExitCode::DataErr);
+        assert_eq!(ExitCode::NoInput, ExitCode::NoInput);
+        assert_ne!(ExitCode::NoInput, ExitCode::NoUser);
+        assert_ne!(ExitCode::NoInput, ExitCode::NoHost);
+        assert_ne!(ExitCode::NoInput, ExitCode::Unavailable);
+        assert_ne!(ExitCode::NoInput, ExitCode::Software);
+        assert_ne!(ExitCode::NoInput, ExitCode::OsErr);
+        assert_ne!(ExitCode::NoInput, ExitCode::OsFile);
+        assert_ne!(ExitCode::NoInput, ExitCode::CantCreat);
+        assert_ne!(ExitCode::NoInput, ExitCode::IoErr);
+        assert_ne!(ExitCode::NoInput, ExitCode::TempFail);
+        assert_ne!(ExitCode::NoInput, ExitCode::Protocol);
+        assert_ne!(ExitCode::NoInput, ExitCode::NoPerm);
+        assert_ne!(ExitCode::NoInput, ExitCode::Config);
+        assert_ne!(ExitCode::NoUser, ExitCode::Ok);
+        assert_ne!(ExitCode::NoUser, ExitCode::Usage);
+        assert_ne!(ExitCode::NoUser, ExitCode::DataErr);
+        assert_ne!(ExitCode::NoUser, ExitCode::NoInput);
+        assert_eq!(ExitCode::NoUser, ExitCode::NoUser);
+        assert_ne!(ExitCode::NoUser, ExitCode::NoHost);
+        assert_ne!(ExitCode::NoUser, ExitCode::Unavailable);
+        assert_ne!(ExitCode::NoUser, ExitCode::Software);
+        assert_ne!(ExitCode::NoUser, ExitCode::OsErr);
+        assert_ne!(ExitCode::NoUser, ExitCode::OsFile);
+        assert_ne!(ExitCode::NoUser, ExitCode::CantCreat);
+        assert_ne!(ExitCode::NoUser, ExitCode::IoErr);
+        assert_ne!(ExitCode::NoUser, ExitCode::TempFail);
+        assert_ne!(ExitCode::NoUser, ExitCode::Protocol);
+        assert_ne!(ExitCode::NoUser, ExitCode::NoPerm);
+        assert_ne!(ExitCode::NoUser, ExitCode::Config);
+        assert_ne!(ExitCode::NoHost, ExitCode::Ok);
+        assert_ne!(ExitCode::NoHost, ExitCode::Usage);
+        assert_ne!(ExitCode::NoHost, ExitCode::DataErr);
+        assert_ne!(ExitCode::NoHost, ExitCode::NoInput);
+        assert_ne!(ExitCode::NoHost, ExitCode::NoUser);
+        assert_eq!(ExitCode::NoHost, ExitCode::NoHost);
+        assert_ne!(ExitCode::NoHost, ExitCode::Unavailable);
+        assert_ne!(ExitCode::NoHost, ExitCode::Software);
+        assert_ne!(ExitCode::NoHost, ExitCode::OsErr);
+        assert_ne!(ExitCode::NoHost, ExitCode::OsFile);
+        assert_ne!(ExitCode::NoHost, ExitCode::CantCreat);
+        assert_ne!(ExitCode::NoHost, ExitCode::IoErr);
+        assert_ne!(ExitCode::NoHost, ExitCode::TempFail);
+        assert_ne!(ExitCode::NoHost, ExitCode::Protocol);
+        assert_ne!(ExitCode::NoHost, ExitCode::NoPerm);
+        assert_ne!(ExitCode::NoHost, ExitCode::Config);
+        assert_ne!(ExitCode::Unavailable, ExitCode::Ok);
+        assert_ne!(ExitCode::Unavailable, ExitCode::Usage);
+        assert_ne!(ExitCode::Unavailable, ExitCode::DataErr);
+        assert_ne!(ExitCode::Unavailable, ExitCode::NoInput);
+        assert_ne!(ExitCode::Unavailable, ExitCode::NoUser);
+        assert_ne!(ExitCode::Unavailable, ExitCode::NoHost);
+        assert_eq!(ExitCode::Unavailable, ExitCode::Unavailable);
+        assert_ne!(ExitCode::Unavailable, ExitCode::Software);
+        assert_ne!(ExitCode::Unavailable, ExitCode::OsErr);
+        assert_ne!(ExitCode::Unavailable, ExitCode::OsFile);
+        assert_ne!(ExitCode::Unavailable, ExitCode::CantCreat);
+        assert_ne!(ExitCode::Unavailable, ExitCode::IoErr);
+        assert_ne!(ExitCode::Unavailable, ExitCode::TempFail);
+        assert_ne!(ExitCode::Unavailable, ExitCode::Protocol);
+        assert_ne!(ExitCode::Unavailable, ExitCode::NoPerm);
+        assert_ne!(ExitCode::Unavailable, ExitCode::Config);
+        assert_ne!(ExitCode::Software, ExitCode::Ok);
+        assert_ne!(ExitCode::Software, ExitCode::Usage);
+        assert_ne!(ExitCode::Software, ExitCode::DataErr);
         assert_ne!(ExitCode::Software, ExitCode::NoInput);
         assert_ne!(ExitCode::Software, ExitCode::NoUser);
         assert_ne!(ExitCode::Software, ExitCode::NoHost);
@@ -585,67 +1995,1595 @@ mod tests {
     }
 
     #[test]
-    fn is_success_for_successful_termination() {
-        assert!(ExitCode::Ok.is_success());
+    fn is_success_for_successful_termination() {
+        assert!(ExitCode::Ok.is_success());
+    }
+
+    #[test]
+    fn is_success_for_unsuccessful_termination() {
+        assert!(!ExitCode::Usage.is_success());
+        assert!(!ExitCode::DataErr.is_success());
+        assert!(!ExitCode::NoInput.is_success());
+        assert!(!ExitCode::NoUser.is_success());
+        assert!(!ExitCode::NoHost.is_success());
+        assert!(!ExitCode::Unavailable.is_success());
+        assert!(!ExitCode::Software.is_success());
+        assert!(!ExitCode::OsErr.is_success());
+        assert!(!ExitCode::OsFile.is_success());
+        assert!(!ExitCode::CantCreat.is_success());
+        assert!(!ExitCode::IoErr.is_success());
+        assert!(!ExitCode::TempFail.is_success());
+        assert!(!ExitCode::Protocol.is_success());
+        assert!(!ExitCode::NoPerm.is_success());
+        assert!(!ExitCode::Config.is_success());
+    }
+
+    #[test]
+    const fn is_success_is_const_fn() {
+        const _: bool = ExitCode::Ok.is_success();
+    }
+
+    #[test]
+    fn is_failure_for_successful_termination() {
+        assert!(!ExitCode::Ok.is_failure());
+    }
+
+    #[test]
+    fn is_failure_for_unsuccessful_termination() {
+        assert!(ExitCode::Usage.is_failure());
+        assert!(ExitCode::DataErr.is_failure());
+        assert!(ExitCode::NoInput.is_failure());
+        assert!(ExitCode::NoUser.is_failure());
+        assert!(ExitCode::NoHost.is_failure());
+        assert!(ExitCode::Unavailable.is_failure());
+        assert!(ExitCode::Software.is_failure());
+        assert!(ExitCode::OsErr.is_failure());
+        assert!(ExitCode::OsFile.is_failure());
+        assert!(ExitCode::CantCreat.is_failure());
+        assert!(ExitCode::IoErr.is_failure());
+        assert!(ExitCode::TempFail.is_failure());
+        assert!(ExitCode::Protocol.is_failure());
+        assert!(ExitCode::NoPerm.is_failure());
+        assert!(ExitCode::Config.is_failure());
+    }
+
+    #[test]
+    const fn is_failure_is_const_fn() {
+        const _: bool = ExitCode::Ok.is_failure();
+    }
+
+    #[test]
+    fn is_os_related() {
+        assert!(!ExitCode::Ok.is_os_related());
+        assert!(!ExitCode::Usage.is_os_related());
+        assert!(!ExitCode::DataErr.is_os_related());
+        assert!(!ExitCode::NoInput.is_os_related());
+        assert!(!ExitCode::NoUser.is_os_related());
+        assert!(!ExitCode::NoHost.is_os_related());
+        assert!(!ExitCode::Unavailable.is_os_related());
+        assert!(!ExitCode::Software.is_os_related());
+        assert!(ExitCode::OsErr.is_os_related());
+        assert!(ExitCode::OsFile.is_os_related());
+        assert!(ExitCode::CantCreat.is_os_related());
+        assert!(ExitCode::IoErr.is_os_related());
+        assert!(!ExitCode::TempFail.is_os_related());
+        assert!(!ExitCode::Protocol.is_os_related());
+        assert!(!ExitCode::NoPerm.is_os_related());
+        assert!(!ExitCode::Config.is_os_related());
+    }
+
+    #[test]
+    fn is_os_related_matches_category() {
+        for code in ExitCode::iter() {
+            assert_eq!(code.is_os_related(), code.category() == Category::System);
+        }
+    }
+
+    #[test]
+    const fn is_os_related_is_const_fn() {
+        const _: bool = ExitCode::OsErr.is_os_related();
+    }
+
+    #[test]
+    fn is_user_error() {
+        assert!(!ExitCode::Ok.is_user_error());
+        assert!(ExitCode::Usage.is_user_error());
+        assert!(ExitCode::DataErr.is_user_error());
+        assert!(ExitCode::NoInput.is_user_error());
+        assert!(ExitCode::NoUser.is_user_error());
+        assert!(ExitCode::NoHost.is_user_error());
+        assert!(!ExitCode::Unavailable.is_user_error());
+        assert!(!ExitCode::Software.is_user_error());
+        assert!(!ExitCode::OsErr.is_user_error());
+        assert!(!ExitCode::OsFile.is_user_error());
+        assert!(!ExitCode::CantCreat.is_user_error());
+        assert!(!ExitCode::IoErr.is_user_error());
+        assert!(!ExitCode::TempFail.is_user_error());
+        assert!(!ExitCode::Protocol.is_user_error());
+        assert!(ExitCode::NoPerm.is_user_error());
+        assert!(!ExitCode::Config.is_user_error());
+    }
+
+    #[test]
+    fn is_user_error_matches_category() {
+        for code in ExitCode::iter() {
+            assert_eq!(code.is_user_error(), code.category() == Category::Usage);
+        }
+    }
+
+    #[test]
+    fn is_user_error_and_is_os_related_are_disjoint() {
+        for code in ExitCode::iter() {
+            assert!(!(code.is_user_error() && code.is_os_related()));
+        }
+    }
+
+    #[test]
+    const fn is_user_error_is_const_fn() {
+        const _: bool = ExitCode::Usage.is_user_error();
+    }
+
+    #[test]
+    fn suggested_backoff_secs() {
+        assert_eq!(ExitCode::Ok.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::Usage.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::DataErr.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::NoInput.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::NoUser.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::NoHost.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::Unavailable.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::Software.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::OsErr.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::OsFile.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::CantCreat.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::IoErr.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::TempFail.suggested_backoff_secs(), Some(1));
+        assert_eq!(ExitCode::Protocol.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::NoPerm.suggested_backoff_secs(), None);
+        assert_eq!(ExitCode::Config.suggested_backoff_secs(), None);
+    }
+
+    #[test]
+    const fn suggested_backoff_secs_is_const_fn() {
+        const _: Option<u32> = ExitCode::TempFail.suggested_backoff_secs();
+    }
+
+    #[test]
+    fn description() {
+        assert_eq!(ExitCode::Ok.description(), "successful termination");
+        assert_eq!(ExitCode::Usage.description(), "command line usage error");
+        assert_eq!(ExitCode::DataErr.description(), "data format error");
+        assert_eq!(ExitCode::NoInput.description(), "cannot open input");
+        assert_eq!(ExitCode::NoUser.description(), "addressee unknown");
+        assert_eq!(ExitCode::NoHost.description(), "host name unknown");
+        assert_eq!(ExitCode::Unavailable.description(), "service unavailable");
+        assert_eq!(ExitCode::Software.description(), "internal software error");
+        assert_eq!(
+            ExitCode::OsErr.description(),
+            "system error (e.g., can't fork)"
+        );
+        assert_eq!(ExitCode::OsFile.description(), "critical OS file missing");
+        assert_eq!(
+            ExitCode::CantCreat.description(),
+            "can't create (user) output file"
+        );
+        assert_eq!(ExitCode::IoErr.description(), "input/output error");
+        assert_eq!(
+            ExitCode::TempFail.description(),
+            "temp failure; user is invited to retry"
+        );
+        assert_eq!(ExitCode::Protocol.description(), "remote error in protocol");
+        assert_eq!(ExitCode::NoPerm.description(), "permission denied");
+        assert_eq!(ExitCode::Config.description(), "configuration error");
+    }
+
+    #[test]
+    const fn description_is_const_fn() {
+        const _: &str = ExitCode::Ok.description();
+    }
+
+    #[test]
+    fn message_for_code() {
+        assert_eq!(
+            ExitCode::message_for_code(0),
+            Some("successful termination")
+        );
+        assert_eq!(
+            ExitCode::message_for_code(64),
+            Some("command line usage error")
+        );
+        assert_eq!(ExitCode::message_for_code(78), Some("configuration error"));
+        for code in ExitCode::iter() {
+            assert_eq!(
+                ExitCode::message_for_code(code.to_u8()),
+                Some(code.description())
+            );
+        }
+    }
+
+    #[test]
+    fn message_for_code_when_invalid() {
+        assert_eq!(ExitCode::message_for_code(1), None);
+        assert_eq!(ExitCode::message_for_code(63), None);
+        assert_eq!(ExitCode::message_for_code(79), None);
+        assert_eq!(ExitCode::message_for_code(u8::MAX), None);
+    }
+
+    #[test]
+    fn as_c_int() {
+        assert_eq!(ExitCode::Ok.as_c_int(), 0);
+        assert_eq!(ExitCode::Usage.as_c_int(), 64);
+        assert_eq!(ExitCode::DataErr.as_c_int(), 65);
+        assert_eq!(ExitCode::NoInput.as_c_int(), 66);
+        assert_eq!(ExitCode::NoUser.as_c_int(), 67);
+        assert_eq!(ExitCode::NoHost.as_c_int(), 68);
+        assert_eq!(ExitCode::Unavailable.as_c_int(), 69);
+        assert_eq!(ExitCode::Software.as_c_int(), 70);
+        assert_eq!(ExitCode::OsErr.as_c_int(), 71);
+        assert_eq!(ExitCode::OsFile.as_c_int(), 72);
+        assert_eq!(ExitCode::CantCreat.as_c_int(), 73);
+        assert_eq!(ExitCode::IoErr.as_c_int(), 74);
+        assert_eq!(ExitCode::TempFail.as_c_int(), 75);
+        assert_eq!(ExitCode::Protocol.as_c_int(), 76);
+        assert_eq!(ExitCode::NoPerm.as_c_int(), 77);
+        assert_eq!(ExitCode::Config.as_c_int(), 78);
+    }
+
+    #[test]
+    const fn as_c_int_is_const_fn() {
+        const _: core::ffi::c_int = ExitCode::Ok.as_c_int();
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn as_raw() {
+        assert_eq!(ExitCode::Ok.as_raw(), 0_i32);
+        assert_eq!(ExitCode::Usage.as_raw(), 64_i32);
+        assert_eq!(ExitCode::DataErr.as_raw(), 65_i32);
+        assert_eq!(ExitCode::Config.as_raw(), 78_i32);
+        for code in ExitCode::iter() {
+            assert_eq!(code.as_raw(), i32::from(code));
+        }
+    }
+
+    #[cfg(all(feature = "std", windows))]
+    #[test]
+    fn as_raw() {
+        assert_eq!(ExitCode::Ok.as_raw(), 0_u32);
+        assert_eq!(ExitCode::Usage.as_raw(), 64_u32);
+        assert_eq!(ExitCode::DataErr.as_raw(), 65_u32);
+        assert_eq!(ExitCode::Config.as_raw(), 78_u32);
+        for code in ExitCode::iter() {
+            assert_eq!(code.as_raw(), u32::from(code));
+        }
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn as_raw_matches_exit_status() {
+        for code in ExitCode::iter() {
+            let status = crate::test_helpers::exit_status_from_code(code.to_u8());
+            assert_eq!(status.code(), Some(code.as_raw()));
+        }
+    }
+
+    #[cfg(all(feature = "std", any(unix, windows)))]
+    #[test]
+    const fn as_raw_is_const_fn() {
+        const _: () = assert!(ExitCode::Usage.as_raw() == 64);
+    }
+
+    #[test]
+    fn into_nonzero_i32() {
+        use core::num::NonZeroI32;
+
+        assert_eq!(ExitCode::Ok.into_nonzero_i32(), None);
+        assert_eq!(ExitCode::Usage.into_nonzero_i32(), NonZeroI32::new(64));
+        assert_eq!(ExitCode::DataErr.into_nonzero_i32(), NonZeroI32::new(65));
+        assert_eq!(ExitCode::NoInput.into_nonzero_i32(), NonZeroI32::new(66));
+        assert_eq!(ExitCode::NoUser.into_nonzero_i32(), NonZeroI32::new(67));
+        assert_eq!(ExitCode::NoHost.into_nonzero_i32(), NonZeroI32::new(68));
+        assert_eq!(
+            ExitCode::Unavailable.into_nonzero_i32(),
+            NonZeroI32::new(69)
+        );
+        assert_eq!(ExitCode::Software.into_nonzero_i32(), NonZeroI32::new(70));
+        assert_eq!(ExitCode::OsErr.into_nonzero_i32(), NonZeroI32::new(71));
+        assert_eq!(ExitCode::OsFile.into_nonzero_i32(), NonZeroI32::new(72));
+        assert_eq!(ExitCode::CantCreat.into_nonzero_i32(), NonZeroI32::new(73));
+        assert_eq!(ExitCode::IoErr.into_nonzero_i32(), NonZeroI32::new(74));
+        assert_eq!(ExitCode::TempFail.into_nonzero_i32(), NonZeroI32::new(75));
+        assert_eq!(ExitCode::Protocol.into_nonzero_i32(), NonZeroI32::new(76));
+        assert_eq!(ExitCode::NoPerm.into_nonzero_i32(), NonZeroI32::new(77));
+        assert_eq!(ExitCode::Config.into_nonzero_i32(), NonZeroI32::new(78));
+    }
+
+    #[test]
+    fn into_nonzero_i32_matches_is_failure() {
+        for code in ExitCode::iter() {
+            assert_eq!(code.into_nonzero_i32().is_some(), code.is_failure());
+        }
+    }
+
+    #[test]
+    const fn into_nonzero_i32_is_const_fn() {
+        const _: Option<core::num::NonZeroI32> = ExitCode::Usage.into_nonzero_i32();
+    }
+
+    #[test]
+    fn try_from_nonzero_i32() {
+        use core::num::NonZeroI32;
+
+        for code in ExitCode::failures() {
+            assert_eq!(
+                code.into_nonzero_i32().map(ExitCode::try_from_nonzero_i32),
+                Some(Ok(code))
+            );
+        }
+        assert_eq!(
+            ExitCode::try_from_nonzero_i32(NonZeroI32::new(64).unwrap()),
+            Ok(ExitCode::Usage)
+        );
+        assert_eq!(
+            ExitCode::try_from_nonzero_i32(NonZeroI32::new(78).unwrap()),
+            Ok(ExitCode::Config)
+        );
+    }
+
+    #[test]
+    fn try_from_nonzero_i32_when_out_of_range() {
+        use core::num::NonZeroI32;
+
+        use crate::error::ExitCodeRangeError;
+
+        for value in [1, 63, 79, 255, -1, i32::MIN, i32::MAX] {
+            assert_eq!(
+                ExitCode::try_from_nonzero_i32(NonZeroI32::new(value).unwrap()),
+                Err(ExitCodeRangeError::new(i128::from(value)))
+            );
+        }
+    }
+
+    #[test]
+    fn as_u8() {
+        assert_eq!(ExitCode::Ok.as_u8(), 0);
+        assert_eq!(ExitCode::Usage.as_u8(), 64);
+        assert_eq!(ExitCode::DataErr.as_u8(), 65);
+        assert_eq!(ExitCode::NoInput.as_u8(), 66);
+        assert_eq!(ExitCode::NoUser.as_u8(), 67);
+        assert_eq!(ExitCode::NoHost.as_u8(), 68);
+        assert_eq!(ExitCode::Unavailable.as_u8(), 69);
+        assert_eq!(ExitCode::Software.as_u8(), 70);
+        assert_eq!(ExitCode::OsErr.as_u8(), 71);
+        assert_eq!(ExitCode::OsFile.as_u8(), 72);
+        assert_eq!(ExitCode::CantCreat.as_u8(), 73);
+        assert_eq!(ExitCode::IoErr.as_u8(), 74);
+        assert_eq!(ExitCode::TempFail.as_u8(), 75);
+        assert_eq!(ExitCode::Protocol.as_u8(), 76);
+        assert_eq!(ExitCode::NoPerm.as_u8(), 77);
+        assert_eq!(ExitCode::Config.as_u8(), 78);
+    }
+
+    #[test]
+    fn as_u8_matches_from() {
+        for code in ExitCode::iter() {
+            assert_eq!(code.as_u8(), u8::from(code));
+        }
+    }
+
+    #[test]
+    const fn as_u8_is_const_fn() {
+        const _: () = assert!(ExitCode::Config.as_u8() == 78);
+    }
+
+    #[test]
+    const fn discriminant() {
+        const _: () = assert!(ExitCode::Ok.discriminant() == 0);
+        const _: () = assert!(ExitCode::Usage.discriminant() == 64);
+        const _: () = assert!(ExitCode::DataErr.discriminant() == 65);
+        const _: () = assert!(ExitCode::NoInput.discriminant() == 66);
+        const _: () = assert!(ExitCode::NoUser.discriminant() == 67);
+        const _: () = assert!(ExitCode::NoHost.discriminant() == 68);
+        const _: () = assert!(ExitCode::Unavailable.discriminant() == 69);
+        const _: () = assert!(ExitCode::Software.discriminant() == 70);
+        const _: () = assert!(ExitCode::OsErr.discriminant() == 71);
+        const _: () = assert!(ExitCode::OsFile.discriminant() == 72);
+        const _: () = assert!(ExitCode::CantCreat.discriminant() == 73);
+        const _: () = assert!(ExitCode::IoErr.discriminant() == 74);
+        const _: () = assert!(ExitCode::TempFail.discriminant() == 75);
+        const _: () = assert!(ExitCode::Protocol.discriminant() == 76);
+        const _: () = assert!(ExitCode::NoPerm.discriminant() == 77);
+        const _: () = assert!(ExitCode::Config.discriminant() == 78);
+    }
+
+    #[test]
+    fn discriminant_matches_from() {
+        for code in ExitCode::iter() {
+            assert_eq!(code.discriminant(), u8::from(code));
+        }
+    }
+
+    #[test]
+    fn severity() {
+        assert_eq!(ExitCode::Ok.severity(), 0);
+        assert_eq!(ExitCode::TempFail.severity(), 1);
+        assert_eq!(ExitCode::Usage.severity(), 2);
+        assert_eq!(ExitCode::DataErr.severity(), 3);
+        assert_eq!(ExitCode::NoInput.severity(), 4);
+        assert_eq!(ExitCode::NoUser.severity(), 5);
+        assert_eq!(ExitCode::NoHost.severity(), 6);
+        assert_eq!(ExitCode::NoPerm.severity(), 7);
+        assert_eq!(ExitCode::Config.severity(), 8);
+        assert_eq!(ExitCode::Unavailable.severity(), 9);
+        assert_eq!(ExitCode::Protocol.severity(), 10);
+        assert_eq!(ExitCode::CantCreat.severity(), 11);
+        assert_eq!(ExitCode::IoErr.severity(), 12);
+        assert_eq!(ExitCode::OsFile.severity(), 13);
+        assert_eq!(ExitCode::OsErr.severity(), 14);
+        assert_eq!(ExitCode::Software.severity(), 15);
+    }
+
+    #[test]
+    const fn severity_is_const_fn() {
+        const _: u8 = ExitCode::Ok.severity();
+    }
+
+    #[test]
+    fn is_at_least_as_severe_as() {
+        assert!(ExitCode::Software.is_at_least_as_severe_as(ExitCode::OsErr));
+        assert!(ExitCode::OsErr.is_at_least_as_severe_as(ExitCode::IoErr));
+        assert!(ExitCode::IoErr.is_at_least_as_severe_as(ExitCode::Config));
+        assert!(ExitCode::Config.is_at_least_as_severe_as(ExitCode::Usage));
+        assert!(ExitCode::Usage.is_at_least_as_severe_as(ExitCode::TempFail));
+        assert!(!ExitCode::OsErr.is_at_least_as_severe_as(ExitCode::Software));
+        assert!(!ExitCode::Usage.is_at_least_as_severe_as(ExitCode::Config));
+        assert!(!ExitCode::TempFail.is_at_least_as_severe_as(ExitCode::Usage));
+    }
+
+    #[test]
+    fn is_at_least_as_severe_as_itself() {
+        for code in ExitCode::iter() {
+            assert!(code.is_at_least_as_severe_as(code));
+        }
+    }
+
+    #[test]
+    fn is_at_least_as_severe_as_ok() {
+        for code in ExitCode::iter() {
+            assert!(code.is_at_least_as_severe_as(ExitCode::Ok));
+            assert_eq!(
+                ExitCode::Ok.is_at_least_as_severe_as(code),
+                code.is_success()
+            );
+        }
+    }
+
+    #[test]
+    const fn is_at_least_as_severe_as_is_const_fn() {
+        const _: bool = ExitCode::Ok.is_at_least_as_severe_as(ExitCode::Ok);
+    }
+
+    #[test]
+    fn next() {
+        assert_eq!(ExitCode::Ok.next(), Some(ExitCode::Usage));
+        assert_eq!(ExitCode::Usage.next(), Some(ExitCode::DataErr));
+        assert_eq!(ExitCode::DataErr.next(), Some(ExitCode::NoInput));
+        assert_eq!(ExitCode::NoInput.next(), Some(ExitCode::NoUser));
+        assert_eq!(ExitCode::NoUser.next(), Some(ExitCode::NoHost));
+        assert_eq!(ExitCode::NoHost.next(), Some(ExitCode::Unavailable));
+        assert_eq!(ExitCode::Unavailable.next(), Some(ExitCode::Software));
+        assert_eq!(ExitCode::Software.next(), Some(ExitCode::OsErr));
+        assert_eq!(ExitCode::OsErr.next(), Some(ExitCode::OsFile));
+        assert_eq!(ExitCode::OsFile.next(), Some(ExitCode::CantCreat));
+        assert_eq!(ExitCode::CantCreat.next(), Some(ExitCode::IoErr));
+        assert_eq!(ExitCode::IoErr.next(), Some(ExitCode::TempFail));
+        assert_eq!(ExitCode::TempFail.next(), Some(ExitCode::Protocol));
+        assert_eq!(ExitCode::Protocol.next(), Some(ExitCode::NoPerm));
+        assert_eq!(ExitCode::NoPerm.next(), Some(ExitCode::Config));
+        assert_eq!(ExitCode::Config.next(), None);
+    }
+
+    #[test]
+    fn next_walks_all_variants() {
+        let mut code = Some(ExitCode::Ok);
+        let mut count = 0;
+        while let Some(c) = code {
+            count += 1;
+            code = c.next();
+        }
+        assert_eq!(count, 16);
+    }
+
+    #[test]
+    const fn next_is_const_fn() {
+        const _: Option<ExitCode> = ExitCode::Ok.next();
+    }
+
+    #[test]
+    fn prev() {
+        assert_eq!(ExitCode::Ok.prev(), None);
+        assert_eq!(ExitCode::Usage.prev(), Some(ExitCode::Ok));
+        assert_eq!(ExitCode::DataErr.prev(), Some(ExitCode::Usage));
+        assert_eq!(ExitCode::NoInput.prev(), Some(ExitCode::DataErr));
+        assert_eq!(ExitCode::NoUser.prev(), Some(ExitCode::NoInput));
+        assert_eq!(ExitCode::NoHost.prev(), Some(ExitCode::NoUser));
+        assert_eq!(ExitCode::Unavailable.prev(), Some(ExitCode::NoHost));
+        assert_eq!(ExitCode::Software.prev(), Some(ExitCode::Unavailable));
+        assert_eq!(ExitCode::OsErr.prev(), Some(ExitCode::Software));
+        assert_eq!(ExitCode::OsFile.prev(), Some(ExitCode::OsErr));
+        assert_eq!(ExitCode::CantCreat.prev(), Some(ExitCode::OsFile));
+        assert_eq!(ExitCode::IoErr.prev(), Some(ExitCode::CantCreat));
+        assert_eq!(ExitCode::TempFail.prev(), Some(ExitCode::IoErr));
+        assert_eq!(ExitCode::Protocol.prev(), Some(ExitCode::TempFail));
+        assert_eq!(ExitCode::NoPerm.prev(), Some(ExitCode::Protocol));
+        assert_eq!(ExitCode::Config.prev(), Some(ExitCode::NoPerm));
+    }
+
+    #[test]
+    fn prev_walks_all_variants() {
+        let mut code = Some(ExitCode::Config);
+        let mut count = 0;
+        while let Some(c) = code {
+            count += 1;
+            code = c.prev();
+        }
+        assert_eq!(count, 16);
+    }
+
+    #[test]
+    const fn prev_is_const_fn() {
+        const _: Option<ExitCode> = ExitCode::Config.prev();
+    }
+
+    #[test]
+    fn from_bool_with_true() {
+        assert_eq!(
+            ExitCode::from_bool_with(true, ExitCode::Usage),
+            ExitCode::Ok
+        );
+        assert_eq!(
+            ExitCode::from_bool_with(true, ExitCode::DataErr),
+            ExitCode::Ok
+        );
+        assert_eq!(ExitCode::from_bool_with(true, ExitCode::Ok), ExitCode::Ok);
+    }
+
+    #[test]
+    fn from_bool_with_false() {
+        assert_eq!(
+            ExitCode::from_bool_with(false, ExitCode::Usage),
+            ExitCode::Usage
+        );
+        assert_eq!(
+            ExitCode::from_bool_with(false, ExitCode::DataErr),
+            ExitCode::DataErr
+        );
+        assert_eq!(ExitCode::from_bool_with(false, ExitCode::Ok), ExitCode::Ok);
+    }
+
+    #[test]
+    const fn from_bool_with_is_const_fn() {
+        const _: ExitCode = ExitCode::from_bool_with(true, ExitCode::Usage);
+    }
+
+    #[test]
+    fn from_outcome_when_matched() {
+        assert_eq!(
+            ExitCode::from_outcome(true, ExitCode::DataErr, None),
+            ExitCode::Ok
+        );
+        assert_eq!(
+            ExitCode::from_outcome(true, ExitCode::Software, None),
+            ExitCode::Ok
+        );
+    }
+
+    #[test]
+    fn from_outcome_when_not_matched() {
+        assert_eq!(
+            ExitCode::from_outcome(false, ExitCode::DataErr, None),
+            ExitCode::DataErr
+        );
+        assert_eq!(
+            ExitCode::from_outcome(false, ExitCode::Software, None),
+            ExitCode::Software
+        );
+        assert_eq!(
+            ExitCode::from_outcome(false, ExitCode::Ok, None),
+            ExitCode::Ok
+        );
+    }
+
+    #[test]
+    fn from_outcome_when_error() {
+        assert_eq!(
+            ExitCode::from_outcome(true, ExitCode::DataErr, Some(ExitCode::NoInput)),
+            ExitCode::NoInput
+        );
+        assert_eq!(
+            ExitCode::from_outcome(false, ExitCode::DataErr, Some(ExitCode::IoErr)),
+            ExitCode::IoErr
+        );
+        assert_eq!(
+            ExitCode::from_outcome(false, ExitCode::DataErr, Some(ExitCode::Ok)),
+            ExitCode::Ok
+        );
+    }
+
+    #[test]
+    const fn from_outcome_is_const_fn() {
+        const _: ExitCode = ExitCode::from_outcome(true, ExitCode::DataErr, None);
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn from_signal() {
+        assert_eq!(ExitCode::from_signal(1), ExitCode::TempFail);
+        assert_eq!(ExitCode::from_signal(15), ExitCode::TempFail);
+        assert_eq!(ExitCode::from_signal(2), ExitCode::OsErr);
+        assert_eq!(ExitCode::from_signal(6), ExitCode::OsErr);
+        assert_eq!(ExitCode::from_signal(9), ExitCode::OsErr);
+        assert_eq!(ExitCode::from_signal(11), ExitCode::OsErr);
+        assert_eq!(ExitCode::from_signal(13), ExitCode::OsErr);
+        assert_eq!(ExitCode::from_signal(0), ExitCode::OsErr);
+        assert_eq!(ExitCode::from_signal(-1), ExitCode::OsErr);
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn from_signal_with_exit_status() {
+        use std::{os::unix::process::ExitStatusExt, process::Command};
+
+        fn get_signal(signal: &str) -> i32 {
+            Command::new("sh")
+                .args(["-c", &format!("kill -{signal} $$")])
+                .status()
+                .unwrap()
+                .signal()
+                .unwrap()
+        }
+
+        assert_eq!(ExitCode::from_signal(get_signal("HUP")), ExitCode::TempFail);
+        assert_eq!(
+            ExitCode::from_signal(get_signal("TERM")),
+            ExitCode::TempFail
+        );
+        assert_eq!(ExitCode::from_signal(get_signal("KILL")), ExitCode::OsErr);
+        assert_eq!(ExitCode::from_signal(get_signal("INT")), ExitCode::OsErr);
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    const fn from_signal_is_const_fn() {
+        const _: ExitCode = ExitCode::from_signal(15);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_shell_status() {
+        assert_eq!(ExitCode::from_shell_status(0), ExitCode::Ok);
+        assert_eq!(ExitCode::from_shell_status(64), ExitCode::Usage);
+        assert_eq!(ExitCode::from_shell_status(65), ExitCode::DataErr);
+        assert_eq!(ExitCode::from_shell_status(78), ExitCode::Config);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_shell_status_when_terminated_by_signal() {
+        assert_eq!(ExitCode::from_shell_status(128), ExitCode::OsErr);
+        // `SIGINT`.
+        assert_eq!(ExitCode::from_shell_status(130), ExitCode::OsErr);
+        // `SIGKILL`.
+        assert_eq!(ExitCode::from_shell_status(137), ExitCode::OsErr);
+        assert_eq!(ExitCode::from_shell_status(255), ExitCode::OsErr);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_shell_status_when_unknown() {
+        assert_eq!(ExitCode::from_shell_status(1), ExitCode::Software);
+        assert_eq!(ExitCode::from_shell_status(2), ExitCode::Software);
+        assert_eq!(ExitCode::from_shell_status(63), ExitCode::Software);
+        assert_eq!(ExitCode::from_shell_status(79), ExitCode::Software);
+        assert_eq!(ExitCode::from_shell_status(127), ExitCode::Software);
+        assert_eq!(ExitCode::from_shell_status(-1), ExitCode::Software);
+    }
+
+    #[test]
+    fn try_from_ascii() {
+        assert_eq!(ExitCode::try_from_ascii(b"0"), Ok(ExitCode::Ok));
+        assert_eq!(ExitCode::try_from_ascii(b"64"), Ok(ExitCode::Usage));
+        assert_eq!(ExitCode::try_from_ascii(b"65"), Ok(ExitCode::DataErr));
+        assert_eq!(ExitCode::try_from_ascii(b"70"), Ok(ExitCode::Software));
+        assert_eq!(ExitCode::try_from_ascii(b"78"), Ok(ExitCode::Config));
+        assert_eq!(ExitCode::try_from_ascii(b"000"), Ok(ExitCode::Ok));
+        assert_eq!(ExitCode::try_from_ascii(b"0064"), Ok(ExitCode::Usage));
+    }
+
+    #[test]
+    fn try_from_ascii_round_trip() {
+        for code in ExitCode::iter() {
+            let s = format!("{code}");
+            assert_eq!(ExitCode::try_from_ascii(s.as_bytes()), Ok(code));
+        }
+    }
+
+    #[test]
+    fn try_from_ascii_when_out_of_range() {
+        assert_eq!(
+            ExitCode::try_from_ascii(b"1"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii(b"63"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii(b"79"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii(b"255"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii(b"256"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii(b"99999999999999999999"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::OutOfRange))
+        );
+    }
+
+    #[test]
+    fn try_from_ascii_when_invalid() {
+        assert_eq!(
+            ExitCode::try_from_ascii(b""),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii(b"Usage"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii(b"+64"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii(b"-0"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii(b" 64"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii(b"64\n"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii(b"6a"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii(b"6x"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii(b"99999999999999999999a"),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber))
+        );
+        assert_eq!(
+            ExitCode::try_from_ascii("６４".as_bytes()),
+            Err(ParseExitCodeError::new(ParseExitCodeErrorKind::NotANumber))
+        );
+    }
+
+    #[test]
+    const fn try_from_ascii_is_const_fn() {
+        const _: Result<ExitCode, ParseExitCodeError> = ExitCode::try_from_ascii(b"0");
+    }
+
+    #[test]
+    fn from_u8_opt() {
+        for value in u8::MIN..=u8::MAX {
+            assert_eq!(ExitCode::from_u8_opt(value), ExitCode::try_from(value).ok());
+        }
+    }
+
+    #[test]
+    fn clamp_from_i32() {
+        assert_eq!(ExitCode::clamp_from_i32(0), ExitCode::Ok);
+        assert_eq!(ExitCode::clamp_from_i32(64), ExitCode::Usage);
+        assert_eq!(ExitCode::clamp_from_i32(65), ExitCode::DataErr);
+        assert_eq!(ExitCode::clamp_from_i32(66), ExitCode::NoInput);
+        assert_eq!(ExitCode::clamp_from_i32(67), ExitCode::NoUser);
+        assert_eq!(ExitCode::clamp_from_i32(68), ExitCode::NoHost);
+        assert_eq!(ExitCode::clamp_from_i32(69), ExitCode::Unavailable);
+        assert_eq!(ExitCode::clamp_from_i32(70), ExitCode::Software);
+        assert_eq!(ExitCode::clamp_from_i32(71), ExitCode::OsErr);
+        assert_eq!(ExitCode::clamp_from_i32(72), ExitCode::OsFile);
+        assert_eq!(ExitCode::clamp_from_i32(73), ExitCode::CantCreat);
+        assert_eq!(ExitCode::clamp_from_i32(74), ExitCode::IoErr);
+        assert_eq!(ExitCode::clamp_from_i32(75), ExitCode::TempFail);
+        assert_eq!(ExitCode::clamp_from_i32(76), ExitCode::Protocol);
+        assert_eq!(ExitCode::clamp_from_i32(77), ExitCode::NoPerm);
+        assert_eq!(ExitCode::clamp_from_i32(78), ExitCode::Config);
+    }
+
+    #[test]
+    fn clamp_from_i32_when_negative() {
+        assert_eq!(ExitCode::clamp_from_i32(-1), ExitCode::OsErr);
+        assert_eq!(ExitCode::clamp_from_i32(-64), ExitCode::OsErr);
+        assert_eq!(ExitCode::clamp_from_i32(i32::MIN), ExitCode::OsErr);
+    }
+
+    #[test]
+    fn clamp_from_i32_when_unknown() {
+        assert_eq!(ExitCode::clamp_from_i32(1), ExitCode::Software);
+        assert_eq!(ExitCode::clamp_from_i32(2), ExitCode::Software);
+        assert_eq!(ExitCode::clamp_from_i32(63), ExitCode::Software);
+        assert_eq!(ExitCode::clamp_from_i32(79), ExitCode::Software);
+        assert_eq!(ExitCode::clamp_from_i32(130), ExitCode::Software);
+        assert_eq!(ExitCode::clamp_from_i32(255), ExitCode::Software);
+        assert_eq!(ExitCode::clamp_from_i32(i32::MAX), ExitCode::Software);
+    }
+
+    #[test]
+    fn saturating_from_i32() {
+        assert_eq!(ExitCode::saturating_from_i32(0), ExitCode::Ok);
+        assert_eq!(ExitCode::saturating_from_i32(64), ExitCode::Usage);
+        assert_eq!(ExitCode::saturating_from_i32(65), ExitCode::DataErr);
+        assert_eq!(ExitCode::saturating_from_i32(66), ExitCode::NoInput);
+        assert_eq!(ExitCode::saturating_from_i32(67), ExitCode::NoUser);
+        assert_eq!(ExitCode::saturating_from_i32(68), ExitCode::NoHost);
+        assert_eq!(ExitCode::saturating_from_i32(69), ExitCode::Unavailable);
+        assert_eq!(ExitCode::saturating_from_i32(70), ExitCode::Software);
+        assert_eq!(ExitCode::saturating_from_i32(71), ExitCode::OsErr);
+        assert_eq!(ExitCode::saturating_from_i32(72), ExitCode::OsFile);
+        assert_eq!(ExitCode::saturating_from_i32(73), ExitCode::CantCreat);
+        assert_eq!(ExitCode::saturating_from_i32(74), ExitCode::IoErr);
+        assert_eq!(ExitCode::saturating_from_i32(75), ExitCode::TempFail);
+        assert_eq!(ExitCode::saturating_from_i32(76), ExitCode::Protocol);
+        assert_eq!(ExitCode::saturating_from_i32(77), ExitCode::NoPerm);
+        assert_eq!(ExitCode::saturating_from_i32(78), ExitCode::Config);
+    }
+
+    #[test]
+    fn saturating_from_i32_when_negative() {
+        assert_eq!(ExitCode::saturating_from_i32(-1), ExitCode::BASE);
+        assert_eq!(ExitCode::saturating_from_i32(-64), ExitCode::BASE);
+        assert_eq!(ExitCode::saturating_from_i32(i32::MIN), ExitCode::BASE);
+    }
+
+    #[test]
+    fn saturating_from_i32_when_below_range() {
+        assert_eq!(ExitCode::saturating_from_i32(1), ExitCode::BASE);
+        assert_eq!(ExitCode::saturating_from_i32(2), ExitCode::BASE);
+        assert_eq!(ExitCode::saturating_from_i32(63), ExitCode::BASE);
+    }
+
+    #[test]
+    fn saturating_from_i32_when_above_range() {
+        assert_eq!(ExitCode::saturating_from_i32(79), ExitCode::MAX);
+        assert_eq!(ExitCode::saturating_from_i32(128), ExitCode::MAX);
+        assert_eq!(ExitCode::saturating_from_i32(255), ExitCode::MAX);
+        assert_eq!(ExitCode::saturating_from_i32(i32::MAX), ExitCode::MAX);
+    }
+
+    #[test]
+    const fn saturating_from_i32_is_const_fn() {
+        const _: ExitCode = ExitCode::saturating_from_i32(0);
+    }
+
+    #[test]
+    fn from_u8_lossy() {
+        assert_eq!(ExitCode::from_u8_lossy(0), ExitCode::Ok);
+        assert_eq!(ExitCode::from_u8_lossy(64), ExitCode::Usage);
+        assert_eq!(ExitCode::from_u8_lossy(65), ExitCode::DataErr);
+        assert_eq!(ExitCode::from_u8_lossy(70), ExitCode::Software);
+        assert_eq!(ExitCode::from_u8_lossy(78), ExitCode::Config);
+        assert_eq!(ExitCode::from_u8_lossy(1), ExitCode::Software);
+        assert_eq!(ExitCode::from_u8_lossy(63), ExitCode::Software);
+        assert_eq!(ExitCode::from_u8_lossy(79), ExitCode::Software);
+        assert_eq!(ExitCode::from_u8_lossy(255), ExitCode::Software);
+    }
+
+    #[test]
+    fn from_u8_lossy_matches_try_from() {
+        for value in 0..=u8::MAX {
+            assert_eq!(
+                ExitCode::from_u8_lossy(value),
+                ExitCode::try_from(value).unwrap_or(ExitCode::Software)
+            );
+        }
+    }
+
+    #[test]
+    const fn from_u8_lossy_is_const_fn() {
+        const _: ExitCode = ExitCode::from_u8_lossy(0);
+    }
+
+    #[test]
+    fn assert_valid_code() {
+        for code in ExitCode::iter() {
+            ExitCode::assert_valid_code(code.to_u8());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "exit code must be `0` or in the range `64..=78`")]
+    fn assert_valid_code_when_one() {
+        ExitCode::assert_valid_code(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "exit code must be `0` or in the range `64..=78`")]
+    fn assert_valid_code_when_63() {
+        ExitCode::assert_valid_code(63);
+    }
+
+    #[test]
+    #[should_panic(expected = "exit code must be `0` or in the range `64..=78`")]
+    fn assert_valid_code_when_79() {
+        ExitCode::assert_valid_code(79);
+    }
+
+    #[test]
+    #[should_panic(expected = "exit code must be `0` or in the range `64..=78`")]
+    fn assert_valid_code_when_max() {
+        ExitCode::assert_valid_code(u8::MAX);
+    }
+
+    #[test]
+    const fn assert_valid_code_is_const_fn() {
+        const _: () = ExitCode::assert_valid_code(0);
+        const _: () = ExitCode::assert_valid_code(64);
+        const _: () = ExitCode::assert_valid_code(78);
+    }
+
+    #[test]
+    fn from_utf8_error() {
+        let bytes = [0xf0, 0x9f, 0x92, 0x96];
+        let err = str::from_utf8(&bytes[..3]).unwrap_err();
+        assert_eq!(ExitCode::from_utf8_error(&err), (ExitCode::DataErr, 0));
+
+        let bytes = *b"foo\xffbar";
+        let err = str::from_utf8(&bytes[..]).unwrap_err();
+        assert_eq!(ExitCode::from_utf8_error(&err), (ExitCode::DataErr, 3));
+
+        let bytes = *b"\xe3\x81\x82\xe3\x81";
+        let err = str::from_utf8(&bytes[..]).unwrap_err();
+        assert_eq!(ExitCode::from_utf8_error(&err), (ExitCode::DataErr, 3));
+    }
+
+    #[test]
+    const fn from_utf8_error_is_const_fn() {
+        #[allow(invalid_from_utf8)]
+        const ERR: core::str::Utf8Error = match str::from_utf8(b"\xff") {
+            Ok(_) => panic!(),
+            Err(err) => err,
+        };
+        const _: (ExitCode, usize) = ExitCode::from_utf8_error(&ERR);
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn is_success_for_unsuccessful_termination() {
-        assert!(!ExitCode::Usage.is_success());
-        assert!(!ExitCode::DataErr.is_success());
-        assert!(!ExitCode::NoInput.is_success());
-        assert!(!ExitCode::NoUser.is_success());
-        assert!(!ExitCode::NoHost.is_success());
-        assert!(!ExitCode::Unavailable.is_success());
-        assert!(!ExitCode::Software.is_success());
-        assert!(!ExitCode::OsErr.is_success());
-        assert!(!ExitCode::OsFile.is_success());
-        assert!(!ExitCode::CantCreat.is_success());
-        assert!(!ExitCode::IoErr.is_success());
-        assert!(!ExitCode::TempFail.is_success());
-        assert!(!ExitCode::Protocol.is_success());
-        assert!(!ExitCode::NoPerm.is_success());
-        assert!(!ExitCode::Config.is_success());
+    fn from_io_error_for_output() {
+        use std::io::{Error, ErrorKind};
+
+        assert_eq!(
+            ExitCode::from_io_error_for_output(&Error::from(ErrorKind::NotFound)),
+            ExitCode::CantCreat
+        );
+        assert_eq!(
+            ExitCode::from_io_error_for_output(&Error::from(ErrorKind::PermissionDenied)),
+            ExitCode::NoPerm
+        );
+        assert_eq!(
+            ExitCode::from_io_error_for_output(&Error::from(ErrorKind::AlreadyExists)),
+            ExitCode::CantCreat
+        );
     }
 
+    #[cfg(all(feature = "std", feature = "extended_io_error"))]
     #[test]
-    const fn is_success_is_const_fn() {
-        const _: bool = ExitCode::Ok.is_success();
+    fn from_io_error_for_output_when_storage_is_full() {
+        use std::io::{Error, ErrorKind};
+
+        let err = Error::from(ErrorKind::StorageFull);
+        assert_eq!(
+            ExitCode::from_io_error_for_output(&err),
+            ExitCode::CantCreat
+        );
+        assert_eq!(ExitCode::from(err), ExitCode::IoErr);
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn is_failure_for_successful_termination() {
-        assert!(!ExitCode::Ok.is_failure());
+    fn from_io_error_for_output_falls_back_to_default_mapping() {
+        use std::io::{Error, ErrorKind};
+
+        for kind in [
+            ErrorKind::ConnectionRefused,
+            ErrorKind::BrokenPipe,
+            ErrorKind::InvalidData,
+            ErrorKind::WriteZero,
+            ErrorKind::Other,
+        ] {
+            let err = Error::from(kind);
+            assert_eq!(
+                ExitCode::from_io_error_for_output(&err),
+                ExitCode::from(err)
+            );
+        }
     }
 
+    #[cfg(all(feature = "std", unix))]
     #[test]
-    fn is_failure_for_unsuccessful_termination() {
-        assert!(ExitCode::Usage.is_failure());
-        assert!(ExitCode::DataErr.is_failure());
-        assert!(ExitCode::NoInput.is_failure());
-        assert!(ExitCode::NoUser.is_failure());
-        assert!(ExitCode::NoHost.is_failure());
-        assert!(ExitCode::Unavailable.is_failure());
-        assert!(ExitCode::Software.is_failure());
-        assert!(ExitCode::OsErr.is_failure());
-        assert!(ExitCode::OsFile.is_failure());
-        assert!(ExitCode::CantCreat.is_failure());
-        assert!(ExitCode::IoErr.is_failure());
-        assert!(ExitCode::TempFail.is_failure());
-        assert!(ExitCode::Protocol.is_failure());
-        assert!(ExitCode::NoPerm.is_failure());
-        assert!(ExitCode::Config.is_failure());
+    fn from_io_error_for_output_when_parent_is_missing() {
+        use std::fs::File;
+
+        let err = File::create("/nonexistent/sysexits/output").unwrap_err();
+        assert_eq!(
+            ExitCode::from_io_error_for_output(&err),
+            ExitCode::CantCreat
+        );
     }
 
+    #[cfg(all(feature = "std", unix))]
     #[test]
-    const fn is_failure_is_const_fn() {
-        const _: bool = ExitCode::Ok.is_failure();
+    fn from_output() {
+        use std::process::Command;
+
+        fn get_output(script: &str) -> std::process::Output {
+            Command::new("sh").args(["-c", script]).output().unwrap()
+        }
+
+        assert_eq!(ExitCode::from_output(&get_output("exit 0")), ExitCode::Ok);
+        assert_eq!(
+            ExitCode::from_output(&get_output("exit 65")),
+            ExitCode::DataErr
+        );
+        assert_eq!(
+            ExitCode::from_output(&get_output("exit 1")),
+            ExitCode::Software
+        );
+        assert_eq!(
+            ExitCode::from_output(&get_output("exit 200")),
+            ExitCode::Software
+        );
+        assert_eq!(
+            ExitCode::from_output(&get_output("exit 130")),
+            ExitCode::Software
+        );
+        assert_eq!(
+            ExitCode::from_output(&get_output("kill -KILL $$")),
+            ExitCode::OsErr
+        );
+
+        let output = get_output("echo foo; echo bar >&2; exit 74");
+        assert_eq!(ExitCode::from_output(&output), ExitCode::IoErr);
+        assert_eq!(output.stdout, b"foo\n");
+        assert_eq!(output.stderr, b"bar\n");
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn from_output_strict() {
+        use std::process::Command;
+
+        use crate::error::TryFromExitStatusError;
+
+        fn get_output(script: &str) -> std::process::Output {
+            Command::new("sh").args(["-c", script]).output().unwrap()
+        }
+
+        assert_eq!(
+            ExitCode::from_output_strict(&get_output("exit 0")),
+            Ok(ExitCode::Ok)
+        );
+        assert_eq!(
+            ExitCode::from_output_strict(&get_output("exit 65")),
+            Ok(ExitCode::DataErr)
+        );
+        assert_eq!(
+            ExitCode::from_output_strict(&get_output("exit 1")),
+            Err(TryFromExitStatusError::new(Some(1)))
+        );
+        assert_eq!(
+            ExitCode::from_output_strict(&get_output("kill -KILL $$")),
+            Err(TryFromExitStatusError::new(None))
+        );
+    }
+
+    #[cfg(all(feature = "std", any(unix, windows)))]
+    #[test]
+    fn to_exit_status() {
+        for code in ExitCode::iter() {
+            let status = code.to_exit_status();
+            assert_eq!(status.code(), Some(code.to_i32()));
+            assert_eq!(status.success(), code.is_success());
+        }
+    }
+
+    #[cfg(all(feature = "std", any(unix, windows)))]
+    #[test]
+    fn to_exit_status_round_trip() {
+        for code in ExitCode::iter() {
+            assert_eq!(ExitCode::try_from(code.to_exit_status()), Ok(code));
+        }
+    }
+
+    #[cfg(all(feature = "std", any(unix, windows)))]
+    #[test]
+    fn to_exit_status_matches_spawned_process() {
+        use crate::test_helpers::exit_status_from_code;
+
+        for code in [ExitCode::Ok, ExitCode::Usage, ExitCode::Config] {
+            assert_eq!(code.to_exit_status(), exit_status_from_code(code.to_u8()));
+        }
+    }
+
+    // `from_env` is called in a child process which is given the environment
+    // variable by `Command::env`, since modifying the environment of this
+    // process races with the other tests which read it.
+    #[cfg(feature = "std")]
+    const FROM_ENV_VAR: &str = "SYSEXITS_TEST_FROM_ENV";
+
+    #[cfg(feature = "std")]
+    const FROM_ENV_CHILD_KEY: &str = "SYSEXITS_TEST_FROM_ENV_CHILD";
+
+    /// Returns the result of `ExitCode::from_env` in a child process where
+    /// `FROM_ENV_VAR` is `value`, or is unset if `value` is [`None`].
+    #[cfg(feature = "std")]
+    fn from_env_in_child(
+        value: Option<&std::ffi::OsStr>,
+    ) -> Option<core::result::Result<ExitCode, ParseExitCodeError>> {
+        use std::{env, process::Command, string::String};
+
+        let mut command = Command::new(env::current_exe().unwrap());
+        command
+            .args(["--exact", "exit_code::tests::from_env_child", "--nocapture"])
+            .env(FROM_ENV_CHILD_KEY, "1");
+        match value {
+            Some(value) => command.env(FROM_ENV_VAR, value),
+            None => command.env_remove(FROM_ENV_VAR),
+        };
+        let output = command.output().unwrap();
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let result = stdout
+            .lines()
+            .find_map(|line| line.split_once("from_env: ").map(|(_, result)| result))
+            .expect("the child process did not call `from_env`");
+        match result {
+            "None" => None,
+            result => Some(result.strip_prefix("Ok ").map_or_else(
+                || {
+                    Err(ParseExitCodeError::new(
+                        match result.strip_prefix("Err ").unwrap() {
+                            "NotANumber" => ParseExitCodeErrorKind::NotANumber,
+                            "OutOfRange" => ParseExitCodeErrorKind::OutOfRange,
+                            "UnknownName" => ParseExitCodeErrorKind::UnknownName,
+                            kind => panic!("unexpected kind: {kind}"),
+                        },
+                    ))
+                },
+                |code| Ok(code.parse().unwrap()),
+            )),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_env_child() {
+        if std::env::var_os(FROM_ENV_CHILD_KEY).is_none() {
+            return;
+        }
+        match ExitCode::from_env(FROM_ENV_VAR) {
+            None => std::println!("from_env: None"),
+            Some(Ok(code)) => std::println!("from_env: Ok {}", code.as_str()),
+            Some(Err(err)) => std::println!("from_env: Err {:?}", err.kind()),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_env() {
+        use std::ffi::OsStr;
+
+        assert_eq!(
+            from_env_in_child(Some(OsStr::new("64"))),
+            Some(Ok(ExitCode::Usage))
+        );
+        assert_eq!(
+            from_env_in_child(Some(OsStr::new("DataErr"))),
+            Some(Ok(ExitCode::DataErr))
+        );
+        assert_eq!(
+            from_env_in_child(Some(OsStr::new("EX_CONFIG"))),
+            Some(Ok(ExitCode::Config))
+        );
+        assert_eq!(from_env_in_child(None), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_env_when_invalid() {
+        use std::ffi::OsStr;
+
+        assert_eq!(
+            from_env_in_child(Some(OsStr::new(""))),
+            Some(Err(ParseExitCodeError::new(
+                ParseExitCodeErrorKind::NotANumber
+            )))
+        );
+        assert_eq!(
+            from_env_in_child(Some(OsStr::new("79"))),
+            Some(Err(ParseExitCodeError::new(
+                ParseExitCodeErrorKind::OutOfRange
+            )))
+        );
+        assert_eq!(
+            from_env_in_child(Some(OsStr::new("usage"))),
+            Some(Err(ParseExitCodeError::new(
+                ParseExitCodeErrorKind::UnknownName
+            )))
+        );
+        assert_eq!(
+            from_env_in_child(Some(OsStr::new("6x"))),
+            Some(Err(ParseExitCodeError::new(
+                ParseExitCodeErrorKind::NotANumber
+            )))
+        );
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn from_env_when_not_unicode() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        assert_eq!(
+            from_env_in_child(Some(OsStr::from_bytes(b"\xff"))),
+            Some(Err(ParseExitCodeError::new(
+                ParseExitCodeErrorKind::UnknownName
+            )))
+        );
+        assert_eq!(
+            from_env_in_child(Some(OsStr::from_bytes(b"6\xff"))),
+            Some(Err(ParseExitCodeError::new(
+                ParseExitCodeErrorKind::NotANumber
+            )))
+        );
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn from_command() {
+        use std::process::Command;
+
+        assert_eq!(
+            ExitCode::from_command(Command::new("sh").args(["-c", "exit 0"])).unwrap(),
+            ExitCode::Ok
+        );
+        assert_eq!(
+            ExitCode::from_command(Command::new("sh").args(["-c", "exit 65"])).unwrap(),
+            ExitCode::DataErr
+        );
+        assert_eq!(
+            ExitCode::from_command(Command::new("sh").args(["-c", "exit 1"])).unwrap(),
+            ExitCode::Software
+        );
+        assert_eq!(
+            ExitCode::from_command(Command::new("sh").args(["-c", "exit 130"])).unwrap(),
+            ExitCode::Software
+        );
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn from_command_when_exit_code_is_greater_than_128() {
+        use std::process::Command;
+
+        assert_eq!(
+            ExitCode::from_command(Command::new("sh").args(["-c", "exit 200"])).unwrap(),
+            ExitCode::Software
+        );
+        assert_eq!(
+            ExitCode::from_command(Command::new("sh").args(["-c", "exit 255"])).unwrap(),
+            ExitCode::Software
+        );
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn from_command_when_terminated_by_signal() {
+        use std::process::Command;
+
+        assert_eq!(
+            ExitCode::from_command(Command::new("sh").args(["-c", "kill -9 $$"])).unwrap(),
+            ExitCode::OsErr
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_command_when_not_found() {
+        use std::process::Command;
+
+        assert!(ExitCode::from_command(&mut Command::new("sysexits-nonexistent-command")).is_err());
+    }
+
+    #[test]
+    fn exit_code_computation() {
+        // This is evaluated in a constant context, so a panic would be a
+        // compile error.
+        const CODES: [i32; 16] = [
+            ExitCode::Ok.to_i32(),
+            ExitCode::Usage.to_i32(),
+            ExitCode::DataErr.to_i32(),
+            ExitCode::NoInput.to_i32(),
+            ExitCode::NoUser.to_i32(),
+            ExitCode::NoHost.to_i32(),
+            ExitCode::Unavailable.to_i32(),
+            ExitCode::Software.to_i32(),
+            ExitCode::OsErr.to_i32(),
+            ExitCode::OsFile.to_i32(),
+            ExitCode::CantCreat.to_i32(),
+            ExitCode::IoErr.to_i32(),
+            ExitCode::TempFail.to_i32(),
+            ExitCode::Protocol.to_i32(),
+            ExitCode::NoPerm.to_i32(),
+            ExitCode::Config.to_i32(),
+        ];
+        assert_eq!(
+            CODES,
+            [0, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78]
+        );
+    }
+
+    #[test]
+    fn exit_code_computation_at_boundaries() {
+        for (value, expected) in [
+            (0, Some(0)),
+            (1, None),
+            (63, None),
+            (64, Some(64)),
+            (78, Some(78)),
+            (79, None),
+            (u8::MAX, None),
+        ] {
+            assert_eq!(
+                ExitCode::try_from(value).ok().map(ExitCode::to_i32),
+                expected
+            );
+        }
+        assert_eq!(ExitCode::Ok.to_i32(), 0);
+        assert_eq!(ExitCode::MAX.to_i32(), 78);
+        for code in ExitCode::iter() {
+            assert_eq!(code.to_i32(), i32::from(code));
+            assert_eq!(code.to_i32(), i32::from(code.to_u8()));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn exit() {
+        use std::{env, process::Command};
+
+        const ENV_KEY: &str = "SYSEXITS_TEST_EXIT";
+        if let Some(code) = env::var_os(ENV_KEY) {
+            let code = ExitCode::try_from(code.to_str().unwrap()).unwrap();
+            code.exit();
+        }
+
+        for code in [ExitCode::Ok, ExitCode::DataErr, ExitCode::Config] {
+            let status = Command::new(env::current_exe().unwrap())
+                .args(["--exact", "exit_code::tests::exit"])
+                .env(ENV_KEY, format!("{code}"))
+                .status()
+                .unwrap();
+            assert_eq!(status.code(), Some(code.to_i32()));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn exit_from_result() {
+        use std::{env, process::Command, string::String};
+
+        const ENV_KEY: &str = "SYSEXITS_TEST_EXIT_FROM_RESULT";
+        if let Some(code) = env::var_os(ENV_KEY) {
+            let code = ExitCode::try_from(code.to_str().unwrap()).unwrap();
+            ExitCode::exit_from_result(if code.is_success() { Ok(()) } else { Err(code) });
+        }
+
+        for code in [ExitCode::Ok, ExitCode::DataErr, ExitCode::Config] {
+            let output = Command::new(env::current_exe().unwrap())
+                .args([
+                    "--exact",
+                    "--nocapture",
+                    "exit_code::tests::exit_from_result",
+                ])
+                .env(ENV_KEY, format!("{code}"))
+                .output()
+                .unwrap();
+            assert_eq!(output.status.code(), Some(code.to_i32()));
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            assert_eq!(
+                stderr.contains(&format!("Error: {code}")),
+                code.is_failure()
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn run() {
+        assert_eq!(
+            format!("{:?}", ExitCode::run(|| Ok::<_, ExitCode>(()))),
+            format!("{:?}", std::process::ExitCode::from(0))
+        );
+        assert_eq!(
+            format!("{:?}", ExitCode::run(|| Err(ExitCode::DataErr))),
+            format!("{:?}", std::process::ExitCode::from(65))
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                ExitCode::run(|| Err(std::io::Error::from(std::io::ErrorKind::NotFound)))
+            ),
+            format!("{:?}", std::process::ExitCode::from(66))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn run_calls_closure_once() {
+        let mut calls = 0;
+        let _ = ExitCode::run(|| {
+            calls += 1;
+            Err(ExitCode::Config)
+        });
+        assert_eq!(calls, 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn run_prints_error() {
+        use std::{env, process::Command, string::String};
+
+        const ENV_KEY: &str = "SYSEXITS_TEST_RUN_PRINTS_ERROR";
+        if let Some(code) = env::var_os(ENV_KEY) {
+            let code = ExitCode::try_from(code.to_str().unwrap()).unwrap();
+            let _ = ExitCode::run(|| if code.is_success() { Ok(()) } else { Err(code) });
+            return;
+        }
+
+        for code in [ExitCode::Ok, ExitCode::DataErr, ExitCode::Config] {
+            let output = Command::new(env::current_exe().unwrap())
+                .args([
+                    "--exact",
+                    "--nocapture",
+                    "exit_code::tests::run_prints_error",
+                ])
+                .env(ENV_KEY, format!("{code}"))
+                .output()
+                .unwrap();
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            assert_eq!(
+                stderr.contains(&format!("Error: {code}")),
+                code.is_failure()
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn set_panic_exit_code() {
+        use std::{env, process::Command, string::String};
+
+        const ENV_KEY: &str = "SYSEXITS_TEST_SET_PANIC_EXIT_CODE";
+
+        if env::var_os(ENV_KEY).is_some() {
+            ExitCode::Software.set_panic_exit_code();
+            panic!("panic in the child process");
+        }
+
+        let output = Command::new(env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "--nocapture",
+                "exit_code::tests::set_panic_exit_code",
+            ])
+            .env(ENV_KEY, "1")
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(70));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("panic in the child process"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn as_process_exit_code() {
+        for code in ExitCode::iter() {
+            assert_eq!(
+                format!("{:?}", code.as_process_exit_code()),
+                format!("{:?}", std::process::ExitCode::from(u8::from(code)))
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn report_to_with_message() {
+        let mut buf = std::vec::Vec::new();
+        let code = ExitCode::NoInput.report_to(&mut buf, Some("Error: no such file"));
+        assert_eq!(buf, b"Error: no such file\n");
+        assert_eq!(
+            format!("{code:?}"),
+            format!("{:?}", std::process::ExitCode::from(66))
+        );
+
+        let mut buf = std::vec::Vec::new();
+        let code = ExitCode::Ok.report_to(&mut buf, Some("done"));
+        assert_eq!(buf, b"done\n");
+        assert_eq!(
+            format!("{code:?}"),
+            format!("{:?}", std::process::ExitCode::SUCCESS)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn report_to_without_message() {
+        let mut buf = std::vec::Vec::new();
+        let code = ExitCode::Config.report_to(&mut buf, None);
+        assert!(buf.is_empty());
+        assert_eq!(
+            format!("{code:?}"),
+            format!("{:?}", std::process::ExitCode::from(78))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn report_to_appends() {
+        let mut buf = b"warning: foo\n".to_vec();
+        let _ = ExitCode::DataErr.report_to(&mut buf, Some("error: bar"));
+        assert_eq!(buf, b"warning: foo\nerror: bar\n");
     }
 
     #[cfg(feature = "std")]
+    #[test]
+    fn report_to_ignores_write_error() {
+        use std::io;
+
+        struct FailingWriter;
+
+        impl io::Write for FailingWriter {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::other("write failed"))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let code = ExitCode::IoErr.report_to(&mut FailingWriter, Some("Error: foo"));
+        assert_eq!(
+            format!("{code:?}"),
+            format!("{:?}", std::process::ExitCode::from(74))
+        );
+    }
+
     #[test]
     fn source() {
-        use std::error::Error;
+        use core::error::Error;
 
         assert!(ExitCode::Ok.source().is_none());
         assert!(ExitCode::Usage.source().is_none());
@@ -665,6 +3603,14 @@ mod tests {
         assert!(ExitCode::Config.source().is_none());
     }
 
+    #[test]
+    fn as_dyn_error() {
+        let err: &dyn core::error::Error = &ExitCode::Usage;
+        assert_eq!(format!("{err}"), "64");
+        assert!(err.source().is_none());
+        assert_eq!(err.downcast_ref::<ExitCode>(), Some(&ExitCode::Usage));
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn report() {
@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Integration of [`ExitCode`] with [`anyhow`].
+
+use super::ExitCode;
+
+impl ExitCode {
+    /// Converts an [`anyhow::Error`] into an `ExitCode`.
+    ///
+    /// If `err` can be downcast to a [`std::io::Error`], this converts it in
+    /// the same way as the [`From`] implementation for [`std::io::Error`].
+    /// Otherwise, this returns [`ExitCode::Software`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let err = anyhow::Error::new(io::Error::from(io::ErrorKind::NotFound));
+    /// assert_eq!(ExitCode::from_anyhow(&err), ExitCode::NoInput);
+    ///
+    /// let err = anyhow::anyhow!("something went wrong");
+    /// assert_eq!(ExitCode::from_anyhow(&err), ExitCode::Software);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_anyhow(err: &anyhow::Error) -> Self {
+        err.downcast_ref::<std::io::Error>()
+            .map_or(Self::Software, |err| err.kind().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+
+    #[test]
+    fn from_anyhow_with_io_error() {
+        assert_eq!(
+            ExitCode::from_anyhow(&anyhow::Error::new(io::Error::from(
+                io::ErrorKind::NotFound
+            ))),
+            ExitCode::NoInput
+        );
+        assert_eq!(
+            ExitCode::from_anyhow(&anyhow::Error::new(io::Error::from(
+                io::ErrorKind::PermissionDenied
+            ))),
+            ExitCode::NoPerm
+        );
+        assert_eq!(
+            ExitCode::from_anyhow(&anyhow::Error::new(io::Error::other("error"))),
+            ExitCode::IoErr
+        );
+    }
+
+    #[test]
+    fn from_anyhow_with_context() {
+        let err = anyhow::Error::new(io::Error::from(io::ErrorKind::NotFound))
+            .context("could not read the file");
+        assert_eq!(ExitCode::from_anyhow(&err), ExitCode::NoInput);
+    }
+
+    #[test]
+    fn from_anyhow_with_message() {
+        assert_eq!(
+            ExitCode::from_anyhow(&anyhow::anyhow!("something went wrong")),
+            ExitCode::Software
+        );
+    }
+
+    #[test]
+    fn from_anyhow_with_other_error() {
+        assert_eq!(
+            ExitCode::from_anyhow(&anyhow::Error::new(ExitCode::Usage)),
+            ExitCode::Software
+        );
+    }
+}
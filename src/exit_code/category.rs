@@ -0,0 +1,181 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Categories of [`ExitCode`].
+
+use core::fmt;
+
+use super::ExitCode;
+
+/// `Category` is a type that represents the broad category of an
+/// [`ExitCode`].
+///
+/// # Examples
+///
+/// ```
+/// # use sysexits::{Category, ExitCode};
+/// #
+/// assert_eq!(ExitCode::Ok.category(), Category::Success);
+/// assert_eq!(ExitCode::Usage.category(), Category::Usage);
+/// assert_eq!(ExitCode::OsErr.category(), Category::System);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Category {
+    /// The successful termination.
+    ///
+    /// This is the category of [`ExitCode::Ok`], and is the default value
+    /// since [`ExitCode::Ok`] is the default value of [`ExitCode`].
+    #[default]
+    Success,
+
+    /// An error caused by the user, such as invalid arguments or input data.
+    ///
+    /// This is the category of [`ExitCode::Usage`], [`ExitCode::DataErr`],
+    /// [`ExitCode::NoInput`], [`ExitCode::NoUser`], [`ExitCode::NoHost`] and
+    /// [`ExitCode::NoPerm`].
+    Usage,
+
+    /// An error caused by a service or a remote system.
+    ///
+    /// This is the category of [`ExitCode::Unavailable`],
+    /// [`ExitCode::TempFail`] and [`ExitCode::Protocol`].
+    Service,
+
+    /// An error caused by the operating system.
+    ///
+    /// This is the category of [`ExitCode::OsErr`], [`ExitCode::OsFile`],
+    /// [`ExitCode::CantCreat`] and [`ExitCode::IoErr`].
+    System,
+
+    /// An internal error of the software, including misconfiguration.
+    ///
+    /// This is the category of [`ExitCode::Software`] and
+    /// [`ExitCode::Config`].
+    Software,
+}
+
+impl fmt::Display for Category {
+    /// Shows the human-readable name of this `Category`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::Category;
+    /// #
+    /// assert_eq!(format!("{}", Category::Success), "success");
+    /// assert_eq!(format!("{}", Category::System), "system error");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Success => "success",
+            Self::Usage => "usage error",
+            Self::Service => "service error",
+            Self::System => "system error",
+            Self::Software => "software error",
+        };
+        name.fmt(f)
+    }
+}
+
+impl ExitCode {
+    /// Returns the [`Category`] of this system exit code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{Category, ExitCode};
+    /// #
+    /// assert_eq!(ExitCode::Ok.category(), Category::Success);
+    /// assert_eq!(ExitCode::Software.category(), Category::Software);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn category(self) -> Category {
+        match self {
+            Self::Ok => Category::Success,
+            Self::Usage
+            | Self::DataErr
+            | Self::NoInput
+            | Self::NoUser
+            | Self::NoHost
+            | Self::NoPerm => Category::Usage,
+            Self::Unavailable | Self::TempFail | Self::Protocol => Category::Service,
+            Self::OsErr | Self::OsFile | Self::CantCreat | Self::IoErr => Category::System,
+            Self::Software | Self::Config => Category::Software,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone() {
+        assert_eq!(Category::Success.clone(), Category::Success);
+    }
+
+    #[test]
+    fn copy() {
+        let a = Category::Success;
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(Category::default(), Category::Success);
+        assert_eq!(Category::default(), ExitCode::Ok.category());
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", Category::Success), "Success");
+        assert_eq!(format!("{:?}", Category::Usage), "Usage");
+        assert_eq!(format!("{:?}", Category::Service), "Service");
+        assert_eq!(format!("{:?}", Category::System), "System");
+        assert_eq!(format!("{:?}", Category::Software), "Software");
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Category::Success), "success");
+        assert_eq!(format!("{}", Category::Usage), "usage error");
+        assert_eq!(format!("{}", Category::Service), "service error");
+        assert_eq!(format!("{}", Category::System), "system error");
+        assert_eq!(format!("{}", Category::Software), "software error");
+    }
+
+    #[test]
+    fn display_with_width() {
+        assert_eq!(format!("{:>16}", Category::Usage), "     usage error");
+        assert_eq!(format!("{:<16}", Category::Usage), "usage error     ");
+    }
+
+    #[test]
+    fn category() {
+        assert_eq!(ExitCode::Ok.category(), Category::Success);
+        assert_eq!(ExitCode::Usage.category(), Category::Usage);
+        assert_eq!(ExitCode::DataErr.category(), Category::Usage);
+        assert_eq!(ExitCode::NoInput.category(), Category::Usage);
+        assert_eq!(ExitCode::NoUser.category(), Category::Usage);
+        assert_eq!(ExitCode::NoHost.category(), Category::Usage);
+        assert_eq!(ExitCode::Unavailable.category(), Category::Service);
+        assert_eq!(ExitCode::Software.category(), Category::Software);
+        assert_eq!(ExitCode::OsErr.category(), Category::System);
+        assert_eq!(ExitCode::OsFile.category(), Category::System);
+        assert_eq!(ExitCode::CantCreat.category(), Category::System);
+        assert_eq!(ExitCode::IoErr.category(), Category::System);
+        assert_eq!(ExitCode::TempFail.category(), Category::Service);
+        assert_eq!(ExitCode::Protocol.category(), Category::Service);
+        assert_eq!(ExitCode::NoPerm.category(), Category::Usage);
+        assert_eq!(ExitCode::Config.category(), Category::Software);
+    }
+
+    #[test]
+    const fn category_is_const_fn() {
+        const _: Category = ExitCode::Ok.category();
+    }
+}
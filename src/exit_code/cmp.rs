@@ -0,0 +1,192 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Comparisons between [`ExitCode`] and integers.
+
+use core::cmp::Ordering;
+
+use super::ExitCode;
+
+impl ExitCode {
+    /// Compares the integer representation of this system exit code with
+    /// `value`.
+    ///
+    /// This is the three-way counterpart of the [`PartialOrd<u8>`]
+    /// implementation, and is useful for reporting the difference between an
+    /// expected and an actual exit code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::cmp::Ordering;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Usage.cmp_code(65), Ordering::Less);
+    /// assert_eq!(ExitCode::Usage.cmp_code(64), Ordering::Equal);
+    /// assert_eq!(ExitCode::Usage.cmp_code(0), Ordering::Greater);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn cmp_code(&self, value: u8) -> Ordering {
+        let code = self.as_u8();
+        if code < value {
+            Ordering::Less
+        } else if code > value {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+impl PartialEq<u8> for ExitCode {
+    /// Compares the integer representation of this `ExitCode` with `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok, 0);
+    /// assert_ne!(ExitCode::Usage, 0);
+    /// ```
+    #[inline]
+    fn eq(&self, other: &u8) -> bool {
+        u8::from(*self) == *other
+    }
+}
+
+impl PartialEq<ExitCode> for u8 {
+    /// Compares `self` with the integer representation of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(0, ExitCode::Ok);
+    /// assert_ne!(0, ExitCode::Usage);
+    /// ```
+    #[inline]
+    fn eq(&self, other: &ExitCode) -> bool {
+        *self == Self::from(*other)
+    }
+}
+
+impl PartialOrd<u8> for ExitCode {
+    /// Compares the integer representation of this `ExitCode` with `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert!(ExitCode::Config > 70);
+    /// assert!(ExitCode::Ok < 64);
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &u8) -> Option<Ordering> {
+        u8::from(*self).partial_cmp(other)
+    }
+}
+
+impl PartialOrd<ExitCode> for u8 {
+    /// Compares `self` with the integer representation of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert!(70 < ExitCode::Config);
+    /// assert!(64 > ExitCode::Ok);
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &ExitCode) -> Option<Ordering> {
+        self.partial_cmp(&Self::from(*other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equality_with_u8() {
+        for code in ExitCode::iter() {
+            assert_eq!(code, u8::from(code));
+            assert_eq!(u8::from(code), code);
+        }
+        assert_ne!(ExitCode::Ok, 1);
+        assert_ne!(1, ExitCode::Ok);
+        assert_ne!(ExitCode::Usage, 65);
+        assert_ne!(65, ExitCode::Usage);
+    }
+
+    #[test]
+    fn partial_cmp_with_u8() {
+        assert!(ExitCode::Config > 70_u8);
+        assert!(ExitCode::Ok < 64_u8);
+        assert!(ExitCode::Software >= 70_u8);
+        assert!(ExitCode::Software <= 70_u8);
+        assert_eq!(ExitCode::Usage.partial_cmp(&64), Some(Ordering::Equal));
+        assert_eq!(ExitCode::Usage.partial_cmp(&65), Some(Ordering::Less));
+        assert_eq!(ExitCode::Usage.partial_cmp(&63), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn partial_cmp_u8_with_exit_code() {
+        assert!(70_u8 < ExitCode::Config);
+        assert!(64_u8 > ExitCode::Ok);
+        assert!(70_u8 >= ExitCode::Software);
+        assert!(70_u8 <= ExitCode::Software);
+        assert_eq!(64.partial_cmp(&ExitCode::Usage), Some(Ordering::Equal));
+        assert_eq!(65.partial_cmp(&ExitCode::Usage), Some(Ordering::Greater));
+        assert_eq!(63.partial_cmp(&ExitCode::Usage), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn cmp_code() {
+        assert_eq!(ExitCode::Ok.cmp_code(0), Ordering::Equal);
+        assert_eq!(ExitCode::Ok.cmp_code(1), Ordering::Less);
+        assert_eq!(ExitCode::Ok.cmp_code(u8::MAX), Ordering::Less);
+        assert_eq!(ExitCode::Usage.cmp_code(63), Ordering::Greater);
+        assert_eq!(ExitCode::Usage.cmp_code(64), Ordering::Equal);
+        assert_eq!(ExitCode::Usage.cmp_code(65), Ordering::Less);
+        assert_eq!(ExitCode::Config.cmp_code(0), Ordering::Greater);
+        assert_eq!(ExitCode::Config.cmp_code(78), Ordering::Equal);
+        assert_eq!(ExitCode::Config.cmp_code(79), Ordering::Less);
+    }
+
+    #[test]
+    fn cmp_code_is_consistent_with_partial_cmp() {
+        for code in ExitCode::iter() {
+            for value in 0..=u8::MAX {
+                assert_eq!(Some(code.cmp_code(value)), code.partial_cmp(&value));
+            }
+        }
+    }
+
+    #[test]
+    const fn cmp_code_is_const_fn() {
+        const _: Ordering = ExitCode::Usage.cmp_code(64);
+    }
+
+    #[test]
+    fn partial_cmp_is_consistent_with_eq() {
+        for code in ExitCode::iter() {
+            for value in 0..=u8::MAX {
+                assert_eq!(
+                    code == value,
+                    code.partial_cmp(&value) == Some(Ordering::Equal)
+                );
+                assert_eq!(
+                    value == code,
+                    value.partial_cmp(&code) == Some(Ordering::Equal)
+                );
+            }
+        }
+    }
+}
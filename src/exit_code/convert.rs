@@ -7,7 +7,9 @@
 //! Implementations of conversions between [`ExitCode`] and other types.
 
 use super::ExitCode;
-use crate::error::ExitCodeRangeError;
+use crate::error::{
+    ExitCodeRangeError, ParseExitCodeError, ParseExitCodeErrorKind, SuccessIsZeroError,
+};
 
 macro_rules! impl_from_exit_code_to_integer {
     ($T:ty, $ok:expr, $usage:expr) => {
@@ -55,6 +57,98 @@ impl_from_exit_code_to_integer!(u64);
 impl_from_exit_code_to_integer!(u128);
 impl_from_exit_code_to_integer!(usize);
 
+macro_rules! impl_exit_code_to_integer {
+    ($f:ident, $T:ty, $ok:expr, $usage:expr) => {
+        impl ExitCode {
+            #[doc = concat!("Converts this `ExitCode` into [`", stringify!($T), "`].")]
+            ///
+            /// This is equivalent to the [`From`] implementation, but can be
+            /// used in const contexts. The resulting value is `0` or `64..=78`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use sysexits::ExitCode;
+            /// #
+            #[doc = $ok]
+            #[doc = $usage]
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn $f(self) -> $T {
+                self as $T
+            }
+        }
+    };
+    ($f:ident, $T:ty) => {
+        impl_exit_code_to_integer!(
+            $f,
+            $T,
+            concat!("assert_eq!(ExitCode::Ok.", stringify!($f), "(), 0);"),
+            concat!("assert_eq!(ExitCode::Usage.", stringify!($f), "(), 64);")
+        );
+    };
+}
+impl_exit_code_to_integer!(to_i8, i8);
+impl_exit_code_to_integer!(to_i16, i16);
+impl_exit_code_to_integer!(to_i32, i32);
+impl_exit_code_to_integer!(to_i64, i64);
+impl_exit_code_to_integer!(to_i128, i128);
+impl_exit_code_to_integer!(to_isize, isize);
+impl_exit_code_to_integer!(to_u8, u8);
+impl_exit_code_to_integer!(to_u16, u16);
+impl_exit_code_to_integer!(to_u32, u32);
+impl_exit_code_to_integer!(to_u64, u64);
+impl_exit_code_to_integer!(to_u128, u128);
+impl_exit_code_to_integer!(to_usize, usize);
+
+impl ExitCode {
+    /// Returns the memory representation of this `ExitCode` as a byte array in
+    /// little-endian byte order.
+    ///
+    /// Since the integer representation of `ExitCode` is a single byte, this is
+    /// the same regardless of the byte order, and is provided for symmetry with
+    /// the integer types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.to_le_bytes(), [0]);
+    /// assert_eq!(ExitCode::Usage.to_le_bytes(), [64]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; 1] {
+        [self.to_u8()]
+    }
+
+    /// Creates an `ExitCode` from its memory representation as a byte array in
+    /// little-endian byte order.
+    ///
+    /// This is the inverse of [`ExitCode::to_le_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the byte is not `0` or `64..=78`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::from_le_bytes([0]), Ok(ExitCode::Ok));
+    /// assert_eq!(ExitCode::from_le_bytes([64]), Ok(ExitCode::Usage));
+    ///
+    /// assert!(ExitCode::from_le_bytes([79]).is_err());
+    /// ```
+    #[inline]
+    pub fn from_le_bytes(bytes: [u8; 1]) -> Result<Self, ExitCodeRangeError> {
+        Self::try_from(u8::from_le_bytes(bytes))
+    }
+}
+
 #[cfg(feature = "std")]
 impl From<ExitCode> for std::process::ExitCode {
     /// Converts an `sysexits::ExitCode` into an [`std::process::ExitCode`].
@@ -66,6 +160,31 @@ impl From<ExitCode> for std::process::ExitCode {
     }
 }
 
+impl TryFrom<ExitCode> for core::num::NonZeroU8 {
+    type Error = SuccessIsZeroError;
+
+    /// Converts an `ExitCode` into a [`NonZeroU8`](core::num::NonZeroU8).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `code` is [`ExitCode::Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::num::NonZeroU8;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(NonZeroU8::try_from(ExitCode::Usage).unwrap().get(), 64);
+    /// assert!(NonZeroU8::try_from(ExitCode::Ok).is_err());
+    /// ```
+    #[inline]
+    fn try_from(code: ExitCode) -> Result<Self, Self::Error> {
+        Self::new(u8::from(code)).ok_or(SuccessIsZeroError::new())
+    }
+}
+
 macro_rules! impl_try_from_integer_to_exit_code {
     ($T:ty, $ok:expr, $usage:expr, $err:expr) => {
         impl TryFrom<$T> for ExitCode {
@@ -106,7 +225,9 @@ macro_rules! impl_try_from_integer_to_exit_code {
                     76 => Ok(Self::Protocol),
                     77 => Ok(Self::NoPerm),
                     78 => Ok(Self::Config),
-                    _ => Err(ExitCodeRangeError),
+                    _ => Err(ExitCodeRangeError::new(
+                        i128::try_from(value).unwrap_or(i128::MAX),
+                    )),
                 }
             }
         }
@@ -145,10 +266,93 @@ impl_try_from_integer_to_exit_code!(u64);
 impl_try_from_integer_to_exit_code!(u128);
 impl_try_from_integer_to_exit_code!(usize);
 
+impl TryFrom<&str> for ExitCode {
+    type Error = ParseExitCodeError;
+
+    /// Parses a string `value` into an `ExitCode`.
+    ///
+    /// If `value` is empty or starts with an ASCII digit, `+` or `-`, it is
+    /// parsed as a decimal integer in the same way as
+    /// [`ExitCode::try_from_ascii`]. Otherwise, it is matched against the
+    /// variant names (e.g., `Usage`) and then against the macro names defined
+    /// in [`<sysexits.h>`] (e.g., `EX_USAGE`). Matching is case-sensitive.
+    ///
+    /// As with the conversion from [`&[u8]`](slice), a leading sign (`+` or
+    /// `-`) is not accepted. Leading or trailing whitespace is not trimmed
+    /// either.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `value` is neither a valid exit code number nor a
+    /// known name. The cause can be obtained with
+    /// [`ParseExitCodeError::kind`]; for example, `6x` is
+    /// [`NotANumber`](ParseExitCodeErrorKind::NotANumber) and `usage` is
+    /// [`UnknownName`](ParseExitCodeErrorKind::UnknownName).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::try_from("0").unwrap(), ExitCode::Ok);
+    /// assert_eq!(ExitCode::try_from("Config").unwrap(), ExitCode::Config);
+    /// assert_eq!(ExitCode::try_from("EX_USAGE").unwrap(), ExitCode::Usage);
+    ///
+    /// assert!(ExitCode::try_from("79").is_err());
+    /// assert!(ExitCode::try_from("usage").is_err());
+    /// ```
+    ///
+    /// [`<sysexits.h>`]: https://man.openbsd.org/sysexits
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.as_bytes().first() {
+            None | Some(b'0'..=b'9' | b'+' | b'-') => Self::try_from_ascii(value.as_bytes()),
+            Some(_) => Self::from_name(value)
+                .ok_or(ParseExitCodeError::new(ParseExitCodeErrorKind::UnknownName)),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for ExitCode {
+    type Error = ParseExitCodeError;
+
+    /// Parses a byte slice `value` of ASCII decimal digits into an `ExitCode`.
+    ///
+    /// This is equivalent to [`ExitCode::try_from_ascii`]. Unlike the
+    /// conversion from [`&str`](str), this does not validate `value` as UTF-8,
+    /// and does not accept names or a sign. Leading zeros are allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `value` is empty, contains a byte which is not an
+    /// ASCII digit, or is not a valid exit code number. The cause can be
+    /// obtained with [`ParseExitCodeError::kind`]. Since names are not
+    /// accepted, the kind is never
+    /// [`UnknownName`](ParseExitCodeErrorKind::UnknownName).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::try_from(&b"0"[..]).unwrap(), ExitCode::Ok);
+    /// assert_eq!(ExitCode::try_from(&b"64"[..]).unwrap(), ExitCode::Usage);
+    ///
+    /// assert!(ExitCode::try_from(&b"79"[..]).is_err());
+    /// assert!(ExitCode::try_from(&b"6x"[..]).is_err());
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_from_ascii(value)
+    }
+}
+
 #[cfg(feature = "std")]
 impl From<std::io::Error> for ExitCode {
     /// Converts an [`Error`](std::io::Error) into an `ExitCode`.
     ///
+    /// This assumes that the error occurred while reading an input. Use
+    /// [`ExitCode::from_io_error_for_output`] for an error which occurred while
+    /// creating or writing an output.
+    ///
     /// # Examples
     ///
     /// ```
@@ -167,10 +371,46 @@ impl From<std::io::Error> for ExitCode {
     }
 }
 
+#[cfg(feature = "std")]
+impl From<&std::io::Error> for ExitCode {
+    /// Converts a reference to an [`Error`](std::io::Error) into an
+    /// `ExitCode`.
+    ///
+    /// This is the same as the conversion from an owned
+    /// [`Error`](std::io::Error), but does not consume the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{Error, ErrorKind};
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let err = Error::from(ErrorKind::NotFound);
+    /// assert_eq!(ExitCode::from(&err), ExitCode::NoInput);
+    /// ```
+    #[inline]
+    fn from(error: &std::io::Error) -> Self {
+        error.kind().into()
+    }
+}
+
 #[cfg(feature = "std")]
 impl From<std::io::ErrorKind> for ExitCode {
     /// Converts an [`ErrorKind`](std::io::ErrorKind) into an `ExitCode`.
     ///
+    /// This assumes that the error occurred while reading an input. For
+    /// example, `ErrorKind::StorageFull` is converted into [`ExitCode::IoErr`].
+    /// Use [`ExitCode::from_io_error_for_output`] for an error which occurred
+    /// while creating or writing an output, which converts it into
+    /// [`ExitCode::CantCreat`] instead.
+    ///
+    /// If the `extended_io_error` feature is enabled, `ErrorKind::ResourceBusy`
+    /// is converted into [`ExitCode::TempFail`], since the resource is
+    /// expected to become available later and retrying may succeed. On the
+    /// other hand, `ErrorKind::Deadlock` is converted into [`ExitCode::IoErr`],
+    /// since retrying the same operation is likely to deadlock again.
+    ///
     /// # Examples
     ///
     /// ```
@@ -195,6 +435,8 @@ impl From<std::io::ErrorKind> for ExitCode {
             | ErrorKind::TimedOut
             | ErrorKind::Interrupted => Self::TempFail,
             #[cfg(feature = "extended_io_error")]
+            ErrorKind::ResourceBusy => Self::TempFail,
+            #[cfg(feature = "extended_io_error")]
             ErrorKind::HostUnreachable | ErrorKind::NetworkUnreachable => Self::NoHost,
             ErrorKind::AddrInUse | ErrorKind::AddrNotAvailable => Self::Unavailable,
             #[cfg(feature = "extended_io_error")]
@@ -248,30 +490,533 @@ impl TryFrom<std::process::ExitStatus> for ExitCode {
     }
 }
 
+#[cfg(feature = "std")]
+impl<W> From<std::io::IntoInnerError<W>> for ExitCode {
+    /// Converts an [`IntoInnerError`](std::io::IntoInnerError) into an
+    /// `ExitCode`.
+    ///
+    /// This reuses the conversion from [`ErrorKind`](std::io::ErrorKind) for
+    /// the underlying [`Error`](std::io::Error) which occurred while flushing
+    /// the buffer. Most such errors are converted into [`ExitCode::IoErr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, BufWriter, Write};
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// #[derive(Debug)]
+    /// struct FailingWriter;
+    ///
+    /// impl Write for FailingWriter {
+    ///     fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+    ///         Err(io::Error::other("failed to write"))
+    ///     }
+    ///
+    ///     fn flush(&mut self) -> io::Result<()> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut writer = BufWriter::new(FailingWriter);
+    /// writer.write_all(b"foo").unwrap();
+    /// let err = writer.into_inner().unwrap_err();
+    /// assert_eq!(ExitCode::from(err), ExitCode::IoErr);
+    /// ```
+    #[inline]
+    fn from(error: std::io::IntoInnerError<W>) -> Self {
+        error.error().kind().into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::sync::mpsc::RecvError> for ExitCode {
+    /// Converts a [`RecvError`](std::sync::mpsc::RecvError) into an
+    /// `ExitCode`.
+    ///
+    /// This always returns [`ExitCode::Software`], because a disconnected
+    /// channel means that the sending side has terminated unexpectedly, which
+    /// is an internal software error rather than a problem with the user's
+    /// input or the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::mpsc;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let (tx, rx) = mpsc::channel::<()>();
+    /// drop(tx);
+    /// assert_eq!(ExitCode::from(rx.recv().unwrap_err()), ExitCode::Software);
+    /// ```
+    #[inline]
+    fn from(_: std::sync::mpsc::RecvError) -> Self {
+        Self::Software
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<std::sync::PoisonError<T>> for ExitCode {
+    /// Converts a [`PoisonError`](std::sync::PoisonError) into an `ExitCode`.
+    ///
+    /// This always returns [`ExitCode::Software`], because a lock is only
+    /// poisoned when another thread panicked while holding it, which is an
+    /// internal software error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::{Mutex, PoisonError};
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let mutex = Mutex::new(());
+    /// let err = PoisonError::new(mutex.lock().unwrap());
+    /// assert_eq!(ExitCode::from(err), ExitCode::Software);
+    /// ```
+    #[inline]
+    fn from(_: std::sync::PoisonError<T>) -> Self {
+        Self::Software
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::path::StripPrefixError> for ExitCode {
+    /// Converts a [`StripPrefixError`](std::path::StripPrefixError) into an
+    /// `ExitCode`.
+    ///
+    /// This always returns [`ExitCode::Software`], because stripping a prefix
+    /// which the path does not start with is usually a logic error in the
+    /// program rather than a problem with the user's input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::path::Path;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let err = Path::new("/a").strip_prefix("/b").unwrap_err();
+    /// assert_eq!(ExitCode::from(err), ExitCode::Software);
+    /// ```
+    #[inline]
+    fn from(_: std::path::StripPrefixError) -> Self {
+        Self::Software
+    }
+}
+
+impl From<core::char::CharTryFromError> for ExitCode {
+    /// Converts a [`CharTryFromError`](core::char::CharTryFromError) into an
+    /// `ExitCode`.
+    ///
+    /// This always returns [`ExitCode::DataErr`], because an integer which is
+    /// not a valid Unicode scalar value is malformed input data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let err = char::try_from(0xd800_u32).unwrap_err();
+    /// assert_eq!(ExitCode::from(err), ExitCode::DataErr);
+    /// ```
+    #[inline]
+    fn from(_: core::char::CharTryFromError) -> Self {
+        Self::DataErr
+    }
+}
+
+impl From<core::char::DecodeUtf16Error> for ExitCode {
+    /// Converts a [`DecodeUtf16Error`](core::char::DecodeUtf16Error) into an
+    /// `ExitCode`.
+    ///
+    /// This always returns [`ExitCode::DataErr`], because an unpaired surrogate
+    /// means that the input is not well-formed UTF-16.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let err = char::decode_utf16([0xd800]).next().unwrap().unwrap_err();
+    /// assert_eq!(ExitCode::from(err), ExitCode::DataErr);
+    /// ```
+    #[inline]
+    fn from(_: core::char::DecodeUtf16Error) -> Self {
+        Self::DataErr
+    }
+}
+
+impl From<core::ffi::FromBytesWithNulError> for ExitCode {
+    /// Converts a [`FromBytesWithNulError`](core::ffi::FromBytesWithNulError)
+    /// into an `ExitCode`.
+    ///
+    /// This always returns [`ExitCode::DataErr`], because bytes which lack a
+    /// terminating nul byte or contain an interior nul byte are malformed input
+    /// data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ffi::CStr;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let err = CStr::from_bytes_with_nul(b"abc").unwrap_err();
+    /// assert_eq!(ExitCode::from(err), ExitCode::DataErr);
+    /// ```
+    #[inline]
+    fn from(_: core::ffi::FromBytesWithNulError) -> Self {
+        Self::DataErr
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[cfg(all(feature = "std", unix))]
-    fn get_exit_status(status: i32) -> std::process::ExitStatus {
-        use std::process::Command;
+    #[test]
+    fn exit_code_to_i8() {
+        assert_eq!(ExitCode::Ok.to_i8(), 0);
+        assert_eq!(ExitCode::Usage.to_i8(), 64);
+        assert_eq!(ExitCode::DataErr.to_i8(), 65);
+        assert_eq!(ExitCode::NoInput.to_i8(), 66);
+        assert_eq!(ExitCode::NoUser.to_i8(), 67);
+        assert_eq!(ExitCode::NoHost.to_i8(), 68);
+        assert_eq!(ExitCode::Unavailable.to_i8(), 69);
+        assert_eq!(ExitCode::Software.to_i8(), 70);
+        assert_eq!(ExitCode::OsErr.to_i8(), 71);
+        assert_eq!(ExitCode::OsFile.to_i8(), 72);
+        assert_eq!(ExitCode::CantCreat.to_i8(), 73);
+        assert_eq!(ExitCode::IoErr.to_i8(), 74);
+        assert_eq!(ExitCode::TempFail.to_i8(), 75);
+        assert_eq!(ExitCode::Protocol.to_i8(), 76);
+        assert_eq!(ExitCode::NoPerm.to_i8(), 77);
+        assert_eq!(ExitCode::Config.to_i8(), 78);
+    }
+
+    #[test]
+    const fn exit_code_to_i8_is_const_fn() {
+        const _: i8 = ExitCode::Ok.to_i8();
+    }
+
+    #[test]
+    fn exit_code_to_i16() {
+        assert_eq!(ExitCode::Ok.to_i16(), 0);
+        assert_eq!(ExitCode::Usage.to_i16(), 64);
+        assert_eq!(ExitCode::DataErr.to_i16(), 65);
+        assert_eq!(ExitCode::NoInput.to_i16(), 66);
+        assert_eq!(ExitCode::NoUser.to_i16(), 67);
+        assert_eq!(ExitCode::NoHost.to_i16(), 68);
+        assert_eq!(ExitCode::Unavailable.to_i16(), 69);
+        assert_eq!(ExitCode::Software.to_i16(), 70);
+        assert_eq!(ExitCode::OsErr.to_i16(), 71);
+        assert_eq!(ExitCode::OsFile.to_i16(), 72);
+        assert_eq!(ExitCode::CantCreat.to_i16(), 73);
+        assert_eq!(ExitCode::IoErr.to_i16(), 74);
+        assert_eq!(ExitCode::TempFail.to_i16(), 75);
+        assert_eq!(ExitCode::Protocol.to_i16(), 76);
+        assert_eq!(ExitCode::NoPerm.to_i16(), 77);
+        assert_eq!(ExitCode::Config.to_i16(), 78);
+    }
+
+    #[test]
+    const fn exit_code_to_i16_is_const_fn() {
+        const _: i16 = ExitCode::Ok.to_i16();
+    }
+
+    #[test]
+    fn exit_code_to_i32() {
+        assert_eq!(ExitCode::Ok.to_i32(), 0);
+        assert_eq!(ExitCode::Usage.to_i32(), 64);
+        assert_eq!(ExitCode::DataErr.to_i32(), 65);
+        assert_eq!(ExitCode::NoInput.to_i32(), 66);
+        assert_eq!(ExitCode::NoUser.to_i32(), 67);
+        assert_eq!(ExitCode::NoHost.to_i32(), 68);
+        assert_eq!(ExitCode::Unavailable.to_i32(), 69);
+        assert_eq!(ExitCode::Software.to_i32(), 70);
+        assert_eq!(ExitCode::OsErr.to_i32(), 71);
+        assert_eq!(ExitCode::OsFile.to_i32(), 72);
+        assert_eq!(ExitCode::CantCreat.to_i32(), 73);
+        assert_eq!(ExitCode::IoErr.to_i32(), 74);
+        assert_eq!(ExitCode::TempFail.to_i32(), 75);
+        assert_eq!(ExitCode::Protocol.to_i32(), 76);
+        assert_eq!(ExitCode::NoPerm.to_i32(), 77);
+        assert_eq!(ExitCode::Config.to_i32(), 78);
+    }
+
+    #[test]
+    const fn exit_code_to_i32_is_const_fn() {
+        const _: i32 = ExitCode::Ok.to_i32();
+    }
+
+    #[test]
+    fn exit_code_to_i64() {
+        assert_eq!(ExitCode::Ok.to_i64(), 0);
+        assert_eq!(ExitCode::Usage.to_i64(), 64);
+        assert_eq!(ExitCode::DataErr.to_i64(), 65);
+        assert_eq!(ExitCode::NoInput.to_i64(), 66);
+        assert_eq!(ExitCode::NoUser.to_i64(), 67);
+        assert_eq!(ExitCode::NoHost.to_i64(), 68);
+        assert_eq!(ExitCode::Unavailable.to_i64(), 69);
+        assert_eq!(ExitCode::Software.to_i64(), 70);
+        assert_eq!(ExitCode::OsErr.to_i64(), 71);
+        assert_eq!(ExitCode::OsFile.to_i64(), 72);
+        assert_eq!(ExitCode::CantCreat.to_i64(), 73);
+        assert_eq!(ExitCode::IoErr.to_i64(), 74);
+        assert_eq!(ExitCode::TempFail.to_i64(), 75);
+        assert_eq!(ExitCode::Protocol.to_i64(), 76);
+        assert_eq!(ExitCode::NoPerm.to_i64(), 77);
+        assert_eq!(ExitCode::Config.to_i64(), 78);
+    }
+
+    #[test]
+    const fn exit_code_to_i64_is_const_fn() {
+        const _: i64 = ExitCode::Ok.to_i64();
+    }
+
+    #[test]
+    fn exit_code_to_i128() {
+        assert_eq!(ExitCode::Ok.to_i128(), 0);
+        assert_eq!(ExitCode::Usage.to_i128(), 64);
+        assert_eq!(ExitCode::DataErr.to_i128(), 65);
+        assert_eq!(ExitCode::NoInput.to_i128(), 66);
+        assert_eq!(ExitCode::NoUser.to_i128(), 67);
+        assert_eq!(ExitCode::NoHost.to_i128(), 68);
+        assert_eq!(ExitCode::Unavailable.to_i128(), 69);
+        assert_eq!(ExitCode::Software.to_i128(), 70);
+        assert_eq!(ExitCode::OsErr.to_i128(), 71);
+        assert_eq!(ExitCode::OsFile.to_i128(), 72);
+        assert_eq!(ExitCode::CantCreat.to_i128(), 73);
+        assert_eq!(ExitCode::IoErr.to_i128(), 74);
+        assert_eq!(ExitCode::TempFail.to_i128(), 75);
+        assert_eq!(ExitCode::Protocol.to_i128(), 76);
+        assert_eq!(ExitCode::NoPerm.to_i128(), 77);
+        assert_eq!(ExitCode::Config.to_i128(), 78);
+    }
+
+    #[test]
+    const fn exit_code_to_i128_is_const_fn() {
+        const _: i128 = ExitCode::Ok.to_i128();
+    }
+
+    #[test]
+    fn exit_code_to_isize() {
+        assert_eq!(ExitCode::Ok.to_isize(), 0);
+        assert_eq!(ExitCode::Usage.to_isize(), 64);
+        assert_eq!(ExitCode::DataErr.to_isize(), 65);
+        assert_eq!(ExitCode::NoInput.to_isize(), 66);
+        assert_eq!(ExitCode::NoUser.to_isize(), 67);
+        assert_eq!(ExitCode::NoHost.to_isize(), 68);
+        assert_eq!(ExitCode::Unavailable.to_isize(), 69);
+        assert_eq!(ExitCode::Software.to_isize(), 70);
+        assert_eq!(ExitCode::OsErr.to_isize(), 71);
+        assert_eq!(ExitCode::OsFile.to_isize(), 72);
+        assert_eq!(ExitCode::CantCreat.to_isize(), 73);
+        assert_eq!(ExitCode::IoErr.to_isize(), 74);
+        assert_eq!(ExitCode::TempFail.to_isize(), 75);
+        assert_eq!(ExitCode::Protocol.to_isize(), 76);
+        assert_eq!(ExitCode::NoPerm.to_isize(), 77);
+        assert_eq!(ExitCode::Config.to_isize(), 78);
+    }
+
+    #[test]
+    const fn exit_code_to_isize_is_const_fn() {
+        const _: isize = ExitCode::Ok.to_isize();
+    }
 
-        Command::new("sh")
-            .arg("-c")
-            .arg(format!("exit {status}"))
-            .status()
-            .unwrap()
+    #[test]
+    fn exit_code_to_u8() {
+        assert_eq!(ExitCode::Ok.to_u8(), 0);
+        assert_eq!(ExitCode::Usage.to_u8(), 64);
+        assert_eq!(ExitCode::DataErr.to_u8(), 65);
+        assert_eq!(ExitCode::NoInput.to_u8(), 66);
+        assert_eq!(ExitCode::NoUser.to_u8(), 67);
+        assert_eq!(ExitCode::NoHost.to_u8(), 68);
+        assert_eq!(ExitCode::Unavailable.to_u8(), 69);
+        assert_eq!(ExitCode::Software.to_u8(), 70);
+        assert_eq!(ExitCode::OsErr.to_u8(), 71);
+        assert_eq!(ExitCode::OsFile.to_u8(), 72);
+        assert_eq!(ExitCode::CantCreat.to_u8(), 73);
+        assert_eq!(ExitCode::IoErr.to_u8(), 74);
+        assert_eq!(ExitCode::TempFail.to_u8(), 75);
+        assert_eq!(ExitCode::Protocol.to_u8(), 76);
+        assert_eq!(ExitCode::NoPerm.to_u8(), 77);
+        assert_eq!(ExitCode::Config.to_u8(), 78);
+    }
+
+    #[test]
+    const fn exit_code_to_u8_is_const_fn() {
+        const _: u8 = ExitCode::Ok.to_u8();
+    }
+
+    #[test]
+    fn exit_code_to_u16() {
+        assert_eq!(ExitCode::Ok.to_u16(), 0);
+        assert_eq!(ExitCode::Usage.to_u16(), 64);
+        assert_eq!(ExitCode::DataErr.to_u16(), 65);
+        assert_eq!(ExitCode::NoInput.to_u16(), 66);
+        assert_eq!(ExitCode::NoUser.to_u16(), 67);
+        assert_eq!(ExitCode::NoHost.to_u16(), 68);
+        assert_eq!(ExitCode::Unavailable.to_u16(), 69);
+        assert_eq!(ExitCode::Software.to_u16(), 70);
+        assert_eq!(ExitCode::OsErr.to_u16(), 71);
+        assert_eq!(ExitCode::OsFile.to_u16(), 72);
+        assert_eq!(ExitCode::CantCreat.to_u16(), 73);
+        assert_eq!(ExitCode::IoErr.to_u16(), 74);
+        assert_eq!(ExitCode::TempFail.to_u16(), 75);
+        assert_eq!(ExitCode::Protocol.to_u16(), 76);
+        assert_eq!(ExitCode::NoPerm.to_u16(), 77);
+        assert_eq!(ExitCode::Config.to_u16(), 78);
+    }
+
+    #[test]
+    const fn exit_code_to_u16_is_const_fn() {
+        const _: u16 = ExitCode::Ok.to_u16();
+    }
+
+    #[test]
+    fn exit_code_to_u32() {
+        assert_eq!(ExitCode::Ok.to_u32(), 0);
+        assert_eq!(ExitCode::Usage.to_u32(), 64);
+        assert_eq!(ExitCode::DataErr.to_u32(), 65);
+        assert_eq!(ExitCode::NoInput.to_u32(), 66);
+        assert_eq!(ExitCode::NoUser.to_u32(), 67);
+        assert_eq!(ExitCode::NoHost.to_u32(), 68);
+        assert_eq!(ExitCode::Unavailable.to_u32(), 69);
+        assert_eq!(ExitCode::Software.to_u32(), 70);
+        assert_eq!(ExitCode::OsErr.to_u32(), 71);
+        assert_eq!(ExitCode::OsFile.to_u32(), 72);
+        assert_eq!(ExitCode::CantCreat.to_u32(), 73);
+        assert_eq!(ExitCode::IoErr.to_u32(), 74);
+        assert_eq!(ExitCode::TempFail.to_u32(), 75);
+        assert_eq!(ExitCode::Protocol.to_u32(), 76);
+        assert_eq!(ExitCode::NoPerm.to_u32(), 77);
+        assert_eq!(ExitCode::Config.to_u32(), 78);
+    }
+
+    #[test]
+    const fn exit_code_to_u32_is_const_fn() {
+        const _: u32 = ExitCode::Ok.to_u32();
     }
 
-    #[cfg(all(feature = "std", windows))]
-    fn get_exit_status(status: u32) -> std::process::ExitStatus {
-        use std::process::Command;
+    #[test]
+    fn exit_code_to_u64() {
+        assert_eq!(ExitCode::Ok.to_u64(), 0);
+        assert_eq!(ExitCode::Usage.to_u64(), 64);
+        assert_eq!(ExitCode::DataErr.to_u64(), 65);
+        assert_eq!(ExitCode::NoInput.to_u64(), 66);
+        assert_eq!(ExitCode::NoUser.to_u64(), 67);
+        assert_eq!(ExitCode::NoHost.to_u64(), 68);
+        assert_eq!(ExitCode::Unavailable.to_u64(), 69);
+        assert_eq!(ExitCode::Software.to_u64(), 70);
+        assert_eq!(ExitCode::OsErr.to_u64(), 71);
+        assert_eq!(ExitCode::OsFile.to_u64(), 72);
+        assert_eq!(ExitCode::CantCreat.to_u64(), 73);
+        assert_eq!(ExitCode::IoErr.to_u64(), 74);
+        assert_eq!(ExitCode::TempFail.to_u64(), 75);
+        assert_eq!(ExitCode::Protocol.to_u64(), 76);
+        assert_eq!(ExitCode::NoPerm.to_u64(), 77);
+        assert_eq!(ExitCode::Config.to_u64(), 78);
+    }
+
+    #[test]
+    const fn exit_code_to_u64_is_const_fn() {
+        const _: u64 = ExitCode::Ok.to_u64();
+    }
+
+    #[test]
+    fn exit_code_to_u128() {
+        assert_eq!(ExitCode::Ok.to_u128(), 0);
+        assert_eq!(ExitCode::Usage.to_u128(), 64);
+        assert_eq!(ExitCode::DataErr.to_u128(), 65);
+        assert_eq!(ExitCode::NoInput.to_u128(), 66);
+        assert_eq!(ExitCode::NoUser.to_u128(), 67);
+        assert_eq!(ExitCode::NoHost.to_u128(), 68);
+        assert_eq!(ExitCode::Unavailable.to_u128(), 69);
+        assert_eq!(ExitCode::Software.to_u128(), 70);
+        assert_eq!(ExitCode::OsErr.to_u128(), 71);
+        assert_eq!(ExitCode::OsFile.to_u128(), 72);
+        assert_eq!(ExitCode::CantCreat.to_u128(), 73);
+        assert_eq!(ExitCode::IoErr.to_u128(), 74);
+        assert_eq!(ExitCode::TempFail.to_u128(), 75);
+        assert_eq!(ExitCode::Protocol.to_u128(), 76);
+        assert_eq!(ExitCode::NoPerm.to_u128(), 77);
+        assert_eq!(ExitCode::Config.to_u128(), 78);
+    }
 
-        Command::new("cmd")
-            .arg("/c")
-            .arg(format!("exit {status}"))
-            .status()
-            .unwrap()
+    #[test]
+    const fn exit_code_to_u128_is_const_fn() {
+        const _: u128 = ExitCode::Ok.to_u128();
+    }
+
+    #[test]
+    fn exit_code_to_usize() {
+        assert_eq!(ExitCode::Ok.to_usize(), 0);
+        assert_eq!(ExitCode::Usage.to_usize(), 64);
+        assert_eq!(ExitCode::DataErr.to_usize(), 65);
+        assert_eq!(ExitCode::NoInput.to_usize(), 66);
+        assert_eq!(ExitCode::NoUser.to_usize(), 67);
+        assert_eq!(ExitCode::NoHost.to_usize(), 68);
+        assert_eq!(ExitCode::Unavailable.to_usize(), 69);
+        assert_eq!(ExitCode::Software.to_usize(), 70);
+        assert_eq!(ExitCode::OsErr.to_usize(), 71);
+        assert_eq!(ExitCode::OsFile.to_usize(), 72);
+        assert_eq!(ExitCode::CantCreat.to_usize(), 73);
+        assert_eq!(ExitCode::IoErr.to_usize(), 74);
+        assert_eq!(ExitCode::TempFail.to_usize(), 75);
+        assert_eq!(ExitCode::Protocol.to_usize(), 76);
+        assert_eq!(ExitCode::NoPerm.to_usize(), 77);
+        assert_eq!(ExitCode::Config.to_usize(), 78);
+    }
+
+    #[test]
+    const fn exit_code_to_usize_is_const_fn() {
+        const _: usize = ExitCode::Ok.to_usize();
+    }
+
+    #[test]
+    fn to_le_bytes() {
+        assert_eq!(ExitCode::Ok.to_le_bytes(), [0]);
+        assert_eq!(ExitCode::Usage.to_le_bytes(), [64]);
+        assert_eq!(ExitCode::DataErr.to_le_bytes(), [65]);
+        assert_eq!(ExitCode::NoInput.to_le_bytes(), [66]);
+        assert_eq!(ExitCode::NoUser.to_le_bytes(), [67]);
+        assert_eq!(ExitCode::NoHost.to_le_bytes(), [68]);
+        assert_eq!(ExitCode::Unavailable.to_le_bytes(), [69]);
+        assert_eq!(ExitCode::Software.to_le_bytes(), [70]);
+        assert_eq!(ExitCode::OsErr.to_le_bytes(), [71]);
+        assert_eq!(ExitCode::OsFile.to_le_bytes(), [72]);
+        assert_eq!(ExitCode::CantCreat.to_le_bytes(), [73]);
+        assert_eq!(ExitCode::IoErr.to_le_bytes(), [74]);
+        assert_eq!(ExitCode::TempFail.to_le_bytes(), [75]);
+        assert_eq!(ExitCode::Protocol.to_le_bytes(), [76]);
+        assert_eq!(ExitCode::NoPerm.to_le_bytes(), [77]);
+        assert_eq!(ExitCode::Config.to_le_bytes(), [78]);
+    }
+
+    #[test]
+    const fn to_le_bytes_is_const_fn() {
+        const _: [u8; 1] = ExitCode::Ok.to_le_bytes();
+    }
+
+    #[test]
+    fn from_le_bytes() {
+        for code in ExitCode::iter() {
+            assert_eq!(ExitCode::from_le_bytes(code.to_le_bytes()), Ok(code));
+        }
+    }
+
+    #[test]
+    fn from_le_bytes_when_out_of_range() {
+        for byte in (1..64).chain(79..=u8::MAX) {
+            assert_eq!(
+                ExitCode::from_le_bytes([byte]),
+                Err(ExitCodeRangeError::new(i128::from(byte)))
+            );
+        }
     }
 
     macro_rules! test_from_exit_code_to_integer {
@@ -310,6 +1055,34 @@ mod tests {
     test_from_exit_code_to_integer!(u128, from_exit_code_to_u128);
     test_from_exit_code_to_integer!(usize, from_exit_code_to_usize);
 
+    #[test]
+    fn try_from_exit_code_to_nonzero_u8() {
+        use core::num::NonZeroU8;
+
+        assert_eq!(
+            NonZeroU8::try_from(ExitCode::Ok).unwrap_err(),
+            SuccessIsZeroError::new()
+        );
+        assert_eq!(NonZeroU8::try_from(ExitCode::Usage).unwrap().get(), 64);
+        assert_eq!(NonZeroU8::try_from(ExitCode::DataErr).unwrap().get(), 65);
+        assert_eq!(NonZeroU8::try_from(ExitCode::NoInput).unwrap().get(), 66);
+        assert_eq!(NonZeroU8::try_from(ExitCode::NoUser).unwrap().get(), 67);
+        assert_eq!(NonZeroU8::try_from(ExitCode::NoHost).unwrap().get(), 68);
+        assert_eq!(
+            NonZeroU8::try_from(ExitCode::Unavailable).unwrap().get(),
+            69
+        );
+        assert_eq!(NonZeroU8::try_from(ExitCode::Software).unwrap().get(), 70);
+        assert_eq!(NonZeroU8::try_from(ExitCode::OsErr).unwrap().get(), 71);
+        assert_eq!(NonZeroU8::try_from(ExitCode::OsFile).unwrap().get(), 72);
+        assert_eq!(NonZeroU8::try_from(ExitCode::CantCreat).unwrap().get(), 73);
+        assert_eq!(NonZeroU8::try_from(ExitCode::IoErr).unwrap().get(), 74);
+        assert_eq!(NonZeroU8::try_from(ExitCode::TempFail).unwrap().get(), 75);
+        assert_eq!(NonZeroU8::try_from(ExitCode::Protocol).unwrap().get(), 76);
+        assert_eq!(NonZeroU8::try_from(ExitCode::NoPerm).unwrap().get(), 77);
+        assert_eq!(NonZeroU8::try_from(ExitCode::Config).unwrap().get(), 78);
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn from_exit_code_to_process_exit_code() {
@@ -421,7 +1194,7 @@ mod tests {
             fn $name() {
                 assert_eq!(
                     ExitCode::try_from(79 as $T).unwrap_err(),
-                    ExitCodeRangeError
+                    ExitCodeRangeError::new(79)
                 );
             }
         };
@@ -475,6 +1248,25 @@ mod tests {
         try_from_usize_to_exit_code_when_out_of_range
     );
 
+    #[test]
+    fn try_from_integer_to_exit_code_when_out_of_range_value() {
+        assert_eq!(ExitCode::try_from(1_u8).unwrap_err().value(), 1);
+        assert_eq!(ExitCode::try_from(255_u8).unwrap_err().value(), 255);
+        assert_eq!(ExitCode::try_from(-1_i32).unwrap_err().value(), -1);
+        assert_eq!(
+            ExitCode::try_from(i128::MIN).unwrap_err().value(),
+            i128::MIN
+        );
+        assert_eq!(
+            ExitCode::try_from(u64::MAX).unwrap_err().value(),
+            i128::from(u64::MAX)
+        );
+        assert_eq!(
+            ExitCode::try_from(u128::MAX).unwrap_err().value(),
+            i128::MAX
+        );
+    }
+
     macro_rules! test_try_from_integer_to_exit_code_when_negative_integer_roundtrip {
         ($T:ty, $name:ident) => {
             #[cfg(feature = "std")]
@@ -482,7 +1274,10 @@ mod tests {
             fn $name(#[strategy(..<$T>::default())] v: $T) {
                 use proptest::prop_assert_eq;
 
-                prop_assert_eq!(ExitCode::try_from(v).unwrap_err(), ExitCodeRangeError);
+                prop_assert_eq!(
+                    ExitCode::try_from(v).unwrap_err().value(),
+                    i128::try_from(v).unwrap_or(i128::MAX)
+                );
             }
         };
     }
@@ -518,7 +1313,10 @@ mod tests {
             fn $name(#[strategy(1..(64 as $T))] v: $T) {
                 use proptest::prop_assert_eq;
 
-                prop_assert_eq!(ExitCode::try_from(v).unwrap_err(), ExitCodeRangeError);
+                prop_assert_eq!(
+                    ExitCode::try_from(v).unwrap_err().value(),
+                    i128::try_from(v).unwrap_or(i128::MAX)
+                );
             }
         };
     }
@@ -578,7 +1376,10 @@ mod tests {
             fn $name(#[strategy((79 as $T)..)] v: $T) {
                 use proptest::prop_assert_eq;
 
-                prop_assert_eq!(ExitCode::try_from(v).unwrap_err(), ExitCodeRangeError);
+                prop_assert_eq!(
+                    ExitCode::try_from(v).unwrap_err().value(),
+                    i128::try_from(v).unwrap_or(i128::MAX)
+                );
             }
         };
     }
@@ -764,7 +1565,7 @@ mod tests {
         #[cfg(feature = "extended_io_error")]
         assert_eq!(
             ExitCode::from(Error::from(ErrorKind::ResourceBusy)),
-            ExitCode::IoErr
+            ExitCode::TempFail
         );
         #[cfg(feature = "extended_io_error")]
         assert_eq!(
@@ -823,6 +1624,30 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_io_error_ref_to_exit_code() {
+        use std::io::{Error, ErrorKind};
+
+        let err = Error::from(ErrorKind::NotFound);
+        assert_eq!(ExitCode::from(&err), ExitCode::NoInput);
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert_eq!(
+            ExitCode::from(&Error::from(ErrorKind::PermissionDenied)),
+            ExitCode::NoPerm
+        );
+        assert_eq!(ExitCode::from(&Error::other("error")), ExitCode::IoErr);
+        for kind in [
+            ErrorKind::NotFound,
+            ErrorKind::BrokenPipe,
+            ErrorKind::InvalidData,
+            ErrorKind::Other,
+        ] {
+            let err = Error::from(kind);
+            assert_eq!(ExitCode::from(&err), ExitCode::from(err));
+        }
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn from_io_error_kind_to_exit_code() {
@@ -935,7 +1760,10 @@ mod tests {
         #[cfg(feature = "extended_io_error")]
         assert_eq!(ExitCode::from(io::ErrorKind::FileTooLarge), ExitCode::IoErr);
         #[cfg(feature = "extended_io_error")]
-        assert_eq!(ExitCode::from(io::ErrorKind::ResourceBusy), ExitCode::IoErr);
+        assert_eq!(
+            ExitCode::from(io::ErrorKind::ResourceBusy),
+            ExitCode::TempFail
+        );
         #[cfg(feature = "extended_io_error")]
         assert_eq!(
             ExitCode::from(io::ErrorKind::ExecutableFileBusy),
@@ -983,67 +1811,67 @@ mod tests {
     #[test]
     fn try_from_process_exit_status_to_exit_code() {
         assert_eq!(
-            ExitCode::try_from(get_exit_status(0)).unwrap(),
+            ExitCode::try_from(ExitCode::Ok.to_exit_status()).unwrap(),
             ExitCode::Ok
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(64)).unwrap(),
+            ExitCode::try_from(ExitCode::Usage.to_exit_status()).unwrap(),
             ExitCode::Usage
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(65)).unwrap(),
+            ExitCode::try_from(ExitCode::DataErr.to_exit_status()).unwrap(),
             ExitCode::DataErr
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(66)).unwrap(),
+            ExitCode::try_from(ExitCode::NoInput.to_exit_status()).unwrap(),
             ExitCode::NoInput
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(67)).unwrap(),
+            ExitCode::try_from(ExitCode::NoUser.to_exit_status()).unwrap(),
             ExitCode::NoUser
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(68)).unwrap(),
+            ExitCode::try_from(ExitCode::NoHost.to_exit_status()).unwrap(),
             ExitCode::NoHost
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(69)).unwrap(),
+            ExitCode::try_from(ExitCode::Unavailable.to_exit_status()).unwrap(),
             ExitCode::Unavailable
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(70)).unwrap(),
+            ExitCode::try_from(ExitCode::Software.to_exit_status()).unwrap(),
             ExitCode::Software
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(71)).unwrap(),
+            ExitCode::try_from(ExitCode::OsErr.to_exit_status()).unwrap(),
             ExitCode::OsErr
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(72)).unwrap(),
+            ExitCode::try_from(ExitCode::OsFile.to_exit_status()).unwrap(),
             ExitCode::OsFile
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(73)).unwrap(),
+            ExitCode::try_from(ExitCode::CantCreat.to_exit_status()).unwrap(),
             ExitCode::CantCreat
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(74)).unwrap(),
+            ExitCode::try_from(ExitCode::IoErr.to_exit_status()).unwrap(),
             ExitCode::IoErr
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(75)).unwrap(),
+            ExitCode::try_from(ExitCode::TempFail.to_exit_status()).unwrap(),
             ExitCode::TempFail
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(76)).unwrap(),
+            ExitCode::try_from(ExitCode::Protocol.to_exit_status()).unwrap(),
             ExitCode::Protocol
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(77)).unwrap(),
+            ExitCode::try_from(ExitCode::NoPerm.to_exit_status()).unwrap(),
             ExitCode::NoPerm
         );
         assert_eq!(
-            ExitCode::try_from(get_exit_status(78)).unwrap(),
+            ExitCode::try_from(ExitCode::Config.to_exit_status()).unwrap(),
             ExitCode::Config
         );
     }
@@ -1052,7 +1880,11 @@ mod tests {
     #[cfg(any(unix, windows))]
     #[test]
     fn try_from_process_exit_status_to_exit_code_when_out_of_range() {
-        use crate::error::TryFromExitStatusError;
+        // `ExitCode::to_exit_status` only creates the exit status of a system
+        // exit code, so a child process is executed for the other values.
+        use crate::{
+            error::TryFromExitStatusError, test_helpers::exit_status_from_code as get_exit_status,
+        };
 
         assert_eq!(
             ExitCode::try_from(get_exit_status(1)).unwrap_err(),
@@ -1091,4 +1923,348 @@ mod tests {
             TryFromExitStatusError::new(None)
         );
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_into_inner_error_to_exit_code() {
+        use std::io::{self, BufWriter, ErrorKind, Write};
+
+        #[derive(Debug)]
+        struct FailingWriter(ErrorKind);
+
+        impl Write for FailingWriter {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(self.0.into())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let into_inner_error = |kind| {
+            let mut writer = BufWriter::new(FailingWriter(kind));
+            writer.write_all(b"foo").unwrap();
+            writer.into_inner().unwrap_err()
+        };
+        assert_eq!(
+            ExitCode::from(into_inner_error(ErrorKind::Other)),
+            ExitCode::IoErr
+        );
+        assert_eq!(
+            ExitCode::from(into_inner_error(ErrorKind::PermissionDenied)),
+            ExitCode::NoPerm
+        );
+        assert_eq!(
+            ExitCode::from(into_inner_error(ErrorKind::BrokenPipe)),
+            ExitCode::TempFail
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_recv_error_to_exit_code() {
+        use std::sync::mpsc;
+
+        let (tx, rx) = mpsc::channel::<()>();
+        drop(tx);
+        assert_eq!(ExitCode::from(rx.recv().unwrap_err()), ExitCode::Software);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_poison_error_to_exit_code() {
+        use std::{
+            sync::{Arc, Mutex},
+            thread,
+        };
+
+        let mutex = Arc::new(Mutex::new(0));
+        {
+            let mutex = Arc::clone(&mutex);
+            let _ = thread::spawn(move || {
+                let _guard = mutex.lock().unwrap();
+                panic!();
+            })
+            .join();
+        }
+        assert!(mutex.is_poisoned());
+        assert_eq!(
+            ExitCode::from(mutex.lock().unwrap_err()),
+            ExitCode::Software
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_strip_prefix_error_to_exit_code() {
+        use std::path::Path;
+
+        assert_eq!(
+            ExitCode::from(Path::new("/a").strip_prefix("/b").unwrap_err()),
+            ExitCode::Software
+        );
+    }
+
+    #[test]
+    fn try_from_str_number_to_exit_code() {
+        assert_eq!(ExitCode::try_from("0").unwrap(), ExitCode::Ok);
+        assert_eq!(ExitCode::try_from("64").unwrap(), ExitCode::Usage);
+        assert_eq!(ExitCode::try_from("70").unwrap(), ExitCode::Software);
+        assert_eq!(ExitCode::try_from("78").unwrap(), ExitCode::Config);
+    }
+
+    #[test]
+    fn try_from_str_variant_name_to_exit_code() {
+        assert_eq!(ExitCode::try_from("Ok").unwrap(), ExitCode::Ok);
+        assert_eq!(ExitCode::try_from("Usage").unwrap(), ExitCode::Usage);
+        assert_eq!(ExitCode::try_from("DataErr").unwrap(), ExitCode::DataErr);
+        assert_eq!(ExitCode::try_from("NoInput").unwrap(), ExitCode::NoInput);
+        assert_eq!(ExitCode::try_from("NoUser").unwrap(), ExitCode::NoUser);
+        assert_eq!(ExitCode::try_from("NoHost").unwrap(), ExitCode::NoHost);
+        assert_eq!(
+            ExitCode::try_from("Unavailable").unwrap(),
+            ExitCode::Unavailable
+        );
+        assert_eq!(ExitCode::try_from("Software").unwrap(), ExitCode::Software);
+        assert_eq!(ExitCode::try_from("OsErr").unwrap(), ExitCode::OsErr);
+        assert_eq!(ExitCode::try_from("OsFile").unwrap(), ExitCode::OsFile);
+        assert_eq!(
+            ExitCode::try_from("CantCreat").unwrap(),
+            ExitCode::CantCreat
+        );
+        assert_eq!(ExitCode::try_from("IoErr").unwrap(), ExitCode::IoErr);
+        assert_eq!(ExitCode::try_from("TempFail").unwrap(), ExitCode::TempFail);
+        assert_eq!(ExitCode::try_from("Protocol").unwrap(), ExitCode::Protocol);
+        assert_eq!(ExitCode::try_from("NoPerm").unwrap(), ExitCode::NoPerm);
+        assert_eq!(ExitCode::try_from("Config").unwrap(), ExitCode::Config);
+    }
+
+    #[test]
+    fn try_from_str_macro_name_to_exit_code() {
+        assert_eq!(ExitCode::try_from("EX_OK").unwrap(), ExitCode::Ok);
+        assert_eq!(ExitCode::try_from("EX_USAGE").unwrap(), ExitCode::Usage);
+        assert_eq!(ExitCode::try_from("EX_DATAERR").unwrap(), ExitCode::DataErr);
+        assert_eq!(ExitCode::try_from("EX_NOINPUT").unwrap(), ExitCode::NoInput);
+        assert_eq!(ExitCode::try_from("EX_NOUSER").unwrap(), ExitCode::NoUser);
+        assert_eq!(ExitCode::try_from("EX_NOHOST").unwrap(), ExitCode::NoHost);
+        assert_eq!(
+            ExitCode::try_from("EX_UNAVAILABLE").unwrap(),
+            ExitCode::Unavailable
+        );
+        assert_eq!(
+            ExitCode::try_from("EX_SOFTWARE").unwrap(),
+            ExitCode::Software
+        );
+        assert_eq!(ExitCode::try_from("EX_OSERR").unwrap(), ExitCode::OsErr);
+        assert_eq!(ExitCode::try_from("EX_OSFILE").unwrap(), ExitCode::OsFile);
+        assert_eq!(
+            ExitCode::try_from("EX_CANTCREAT").unwrap(),
+            ExitCode::CantCreat
+        );
+        assert_eq!(ExitCode::try_from("EX_IOERR").unwrap(), ExitCode::IoErr);
+        assert_eq!(
+            ExitCode::try_from("EX_TEMPFAIL").unwrap(),
+            ExitCode::TempFail
+        );
+        assert_eq!(
+            ExitCode::try_from("EX_PROTOCOL").unwrap(),
+            ExitCode::Protocol
+        );
+        assert_eq!(ExitCode::try_from("EX_NOPERM").unwrap(), ExitCode::NoPerm);
+        assert_eq!(ExitCode::try_from("EX_CONFIG").unwrap(), ExitCode::Config);
+    }
+
+    #[test]
+    fn try_from_invalid_str_to_exit_code() {
+        assert_eq!(
+            ExitCode::try_from("").unwrap_err().kind(),
+            ParseExitCodeErrorKind::NotANumber
+        );
+        assert_eq!(
+            ExitCode::try_from("1").unwrap_err().kind(),
+            ParseExitCodeErrorKind::OutOfRange
+        );
+        assert_eq!(
+            ExitCode::try_from("79").unwrap_err().kind(),
+            ParseExitCodeErrorKind::OutOfRange
+        );
+        assert_eq!(
+            ExitCode::try_from("256").unwrap_err().kind(),
+            ParseExitCodeErrorKind::OutOfRange
+        );
+        assert_eq!(
+            ExitCode::try_from("-1").unwrap_err().kind(),
+            ParseExitCodeErrorKind::NotANumber
+        );
+        assert_eq!(
+            ExitCode::try_from("usage").unwrap_err().kind(),
+            ParseExitCodeErrorKind::UnknownName
+        );
+        assert_eq!(
+            ExitCode::try_from("ex_usage").unwrap_err().kind(),
+            ParseExitCodeErrorKind::UnknownName
+        );
+        assert_eq!(
+            ExitCode::try_from(" Usage").unwrap_err().kind(),
+            ParseExitCodeErrorKind::UnknownName
+        );
+        assert_eq!(
+            ExitCode::try_from("EX_FOO").unwrap_err().kind(),
+            ParseExitCodeErrorKind::UnknownName
+        );
+    }
+
+    #[test]
+    fn try_from_str_with_sign_to_exit_code() {
+        for s in ["+0", "-0", "+64", "-64", "+", "-", "+Usage"] {
+            assert_eq!(
+                ExitCode::try_from(s).unwrap_err().kind(),
+                ParseExitCodeErrorKind::NotANumber
+            );
+            assert_eq!(
+                ExitCode::try_from(s).unwrap_err().kind(),
+                ExitCode::try_from(s.as_bytes()).unwrap_err().kind()
+            );
+        }
+    }
+
+    #[test]
+    fn try_from_str_with_whitespace_to_exit_code() {
+        for s in [" 64", "64 ", "\t64", "64\n", " Usage", "Usage "] {
+            assert!(ExitCode::try_from(s).is_err());
+        }
+        assert_eq!(
+            ExitCode::try_from(" 64").unwrap_err().kind(),
+            ParseExitCodeErrorKind::UnknownName
+        );
+        assert_eq!(
+            ExitCode::try_from("64 ").unwrap_err().kind(),
+            ParseExitCodeErrorKind::NotANumber
+        );
+        assert_eq!(
+            ExitCode::try_from("64\n").unwrap_err().kind(),
+            ParseExitCodeErrorKind::NotANumber
+        );
+    }
+
+    #[test]
+    fn try_from_str_with_trailing_non_digit_to_exit_code() {
+        for s in ["6x", "64a", "0x40", "64_", "6.4"] {
+            assert_eq!(
+                ExitCode::try_from(s).unwrap_err().kind(),
+                ParseExitCodeErrorKind::NotANumber
+            );
+            assert_eq!(
+                ExitCode::try_from(s).unwrap_err().kind(),
+                ExitCode::try_from(s.as_bytes()).unwrap_err().kind()
+            );
+            assert_eq!(
+                ExitCode::try_from(s).unwrap_err().kind(),
+                ExitCode::try_from_ascii(s.as_bytes()).unwrap_err().kind()
+            );
+        }
+    }
+
+    #[test]
+    fn try_from_bytes_to_exit_code() {
+        assert_eq!(ExitCode::try_from(&b"0"[..]).unwrap(), ExitCode::Ok);
+        assert_eq!(ExitCode::try_from(&b"64"[..]).unwrap(), ExitCode::Usage);
+        assert_eq!(ExitCode::try_from(&b"70"[..]).unwrap(), ExitCode::Software);
+        assert_eq!(ExitCode::try_from(&b"78"[..]).unwrap(), ExitCode::Config);
+        assert_eq!(ExitCode::try_from(&b"0064"[..]).unwrap(), ExitCode::Usage);
+    }
+
+    #[test]
+    fn try_from_bytes_to_exit_code_matches_str() {
+        for code in ExitCode::iter() {
+            let s = format!("{code}");
+            assert_eq!(ExitCode::try_from(s.as_bytes()), ExitCode::try_from(&*s));
+        }
+    }
+
+    #[test]
+    fn try_from_invalid_bytes_to_exit_code() {
+        assert_eq!(
+            ExitCode::try_from(&b""[..]).unwrap_err().kind(),
+            ParseExitCodeErrorKind::NotANumber
+        );
+        assert_eq!(
+            ExitCode::try_from(&b"6x"[..]).unwrap_err().kind(),
+            ParseExitCodeErrorKind::NotANumber
+        );
+        assert_eq!(
+            ExitCode::try_from(&b"-1"[..]).unwrap_err().kind(),
+            ParseExitCodeErrorKind::NotANumber
+        );
+        assert_eq!(
+            ExitCode::try_from(&b" 64"[..]).unwrap_err().kind(),
+            ParseExitCodeErrorKind::NotANumber
+        );
+        assert_eq!(
+            ExitCode::try_from(&b"EX_USAGE"[..]).unwrap_err().kind(),
+            ParseExitCodeErrorKind::NotANumber
+        );
+        assert_eq!(
+            ExitCode::try_from(&b"1"[..]).unwrap_err().kind(),
+            ParseExitCodeErrorKind::OutOfRange
+        );
+        assert_eq!(
+            ExitCode::try_from(&b"79"[..]).unwrap_err().kind(),
+            ParseExitCodeErrorKind::OutOfRange
+        );
+        assert_eq!(
+            ExitCode::try_from(&b"256"[..]).unwrap_err().kind(),
+            ParseExitCodeErrorKind::OutOfRange
+        );
+        assert_eq!(
+            ExitCode::try_from(&b"99999999999999999999999999999999999999999"[..])
+                .unwrap_err()
+                .kind(),
+            ParseExitCodeErrorKind::OutOfRange
+        );
+    }
+
+    #[test]
+    fn from_char_try_from_error_to_exit_code() {
+        assert_eq!(
+            ExitCode::from(char::try_from(0xd800_u32).unwrap_err()),
+            ExitCode::DataErr
+        );
+        assert_eq!(
+            ExitCode::from(char::try_from(0x0011_0000_u32).unwrap_err()),
+            ExitCode::DataErr
+        );
+    }
+
+    #[test]
+    fn from_decode_utf16_error_to_exit_code() {
+        assert_eq!(
+            ExitCode::from(char::decode_utf16([0xd800]).next().unwrap().unwrap_err()),
+            ExitCode::DataErr
+        );
+        assert_eq!(
+            ExitCode::from(char::decode_utf16([0xdc00]).next().unwrap().unwrap_err()),
+            ExitCode::DataErr
+        );
+    }
+
+    #[test]
+    fn from_from_bytes_with_nul_error_to_exit_code() {
+        use core::ffi::CStr;
+
+        assert_eq!(
+            ExitCode::from(CStr::from_bytes_with_nul(b"abc").unwrap_err()),
+            ExitCode::DataErr
+        );
+        let interior_nul: &[u8] = b"a\0bc\0";
+        assert_eq!(
+            ExitCode::from(CStr::from_bytes_with_nul(interior_nul).unwrap_err()),
+            ExitCode::DataErr
+        );
+        assert_eq!(
+            ExitCode::from(CStr::from_bytes_with_nul(b"").unwrap_err()),
+            ExitCode::DataErr
+        );
+    }
 }
@@ -0,0 +1,193 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A machine-readable representation of [`ExitCode`].
+
+use super::ExitCode;
+
+/// `ExitCodeDescriptor` is a type that describes an [`ExitCode`].
+///
+/// This collects the integer representation and the names of a system exit
+/// code into a single value, which is useful for machine-readable output.
+/// Under the `serde` feature, this is serialized as an object such as
+/// `{"code":64,"name":"Usage","macro":"EX_USAGE","message":"command line usage
+/// error"}`.
+///
+/// # Examples
+///
+/// ```
+/// # use sysexits::ExitCode;
+/// #
+/// let descriptor = ExitCode::Usage.to_descriptor();
+/// assert_eq!(descriptor.code, 64);
+/// assert_eq!(descriptor.name, "Usage");
+/// assert_eq!(descriptor.macro_name, "EX_USAGE");
+/// assert_eq!(descriptor.message, "command line usage error");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ExitCodeDescriptor {
+    /// The integer representation (see [`ExitCode::to_u8`]).
+    pub code: u8,
+
+    /// The canonical name (see [`ExitCode::as_str`]).
+    pub name: &'static str,
+
+    /// The alias of the name, which is the name of the macro defined in
+    /// [`<sysexits.h>`] (see [`ExitCode::name`]).
+    ///
+    /// [`<sysexits.h>`]: https://man.openbsd.org/sysexits
+    pub macro_name: &'static str,
+
+    /// The short description (see [`ExitCode::description`]).
+    pub message: &'static str,
+}
+
+impl ExitCode {
+    /// Returns the [`ExitCodeDescriptor`] of this system exit code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let descriptor = ExitCode::DataErr.to_descriptor();
+    /// assert_eq!(descriptor.code, 65);
+    /// assert_eq!(descriptor.name, "DataErr");
+    /// assert_eq!(descriptor.macro_name, "EX_DATAERR");
+    /// assert_eq!(descriptor.message, "data format error");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_descriptor(self) -> ExitCodeDescriptor {
+        ExitCodeDescriptor {
+            code: self.to_u8(),
+            name: self.as_str(),
+            macro_name: self.name(),
+            message: self.description(),
+        }
+    }
+}
+
+impl From<ExitCode> for ExitCodeDescriptor {
+    /// Converts an `ExitCode` into an `ExitCodeDescriptor`.
+    #[inline]
+    fn from(code: ExitCode) -> Self {
+        code.to_descriptor()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ExitCodeDescriptor {
+    /// Serializes this `ExitCodeDescriptor` as a struct with the fields
+    /// `code`, `name`, `macro` and `message`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(
+    ///     serde_json::to_string(&ExitCode::Usage.to_descriptor()).unwrap(),
+    ///     r#"{"code":64,"name":"Usage","macro":"EX_USAGE","message":"command line usage error"}"#
+    /// );
+    /// ```
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("ExitCodeDescriptor", 4)?;
+        state.serialize_field("code", &self.code)?;
+        state.serialize_field("name", self.name)?;
+        state.serialize_field("macro", self.macro_name)?;
+        state.serialize_field("message", self.message)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_descriptor() {
+        assert_eq!(
+            ExitCode::Ok.to_descriptor(),
+            ExitCodeDescriptor {
+                code: 0,
+                name: "Ok",
+                macro_name: "EX_OK",
+                message: "successful termination"
+            }
+        );
+        assert_eq!(
+            ExitCode::Usage.to_descriptor(),
+            ExitCodeDescriptor {
+                code: 64,
+                name: "Usage",
+                macro_name: "EX_USAGE",
+                message: "command line usage error"
+            }
+        );
+        assert_eq!(
+            ExitCode::DataErr.to_descriptor(),
+            ExitCodeDescriptor {
+                code: 65,
+                name: "DataErr",
+                macro_name: "EX_DATAERR",
+                message: "data format error"
+            }
+        );
+        assert_eq!(
+            ExitCode::Config.to_descriptor(),
+            ExitCodeDescriptor {
+                code: 78,
+                name: "Config",
+                macro_name: "EX_CONFIG",
+                message: "configuration error"
+            }
+        );
+    }
+
+    #[test]
+    fn to_descriptor_matches_accessors() {
+        for code in ExitCode::iter() {
+            let descriptor = code.to_descriptor();
+            assert_eq!(descriptor.code, code.to_u8());
+            assert_eq!(descriptor.name, code.as_str());
+            assert_eq!(descriptor.macro_name, code.name());
+            assert_eq!(descriptor.message, code.description());
+        }
+    }
+
+    #[test]
+    const fn to_descriptor_is_const_fn() {
+        const _: ExitCodeDescriptor = ExitCode::Usage.to_descriptor();
+    }
+
+    #[test]
+    fn from_exit_code_to_descriptor() {
+        assert_eq!(
+            ExitCodeDescriptor::from(ExitCode::Usage),
+            ExitCode::Usage.to_descriptor()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&ExitCode::Ok.to_descriptor()).unwrap(),
+            r#"{"code":0,"name":"Ok","macro":"EX_OK","message":"successful termination"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&ExitCode::Usage.to_descriptor()).unwrap(),
+            r#"{"code":64,"name":"Usage","macro":"EX_USAGE","message":"command line usage error"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&ExitCode::Config.to_descriptor()).unwrap(),
+            r#"{"code":78,"name":"Config","macro":"EX_CONFIG","message":"configuration error"}"#
+        );
+    }
+}
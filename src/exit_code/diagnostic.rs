@@ -0,0 +1,196 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Integration of [`ExitCode`] with [`miette`].
+
+use core::fmt;
+use std::boxed::Box;
+
+use super::ExitCode;
+
+/// `ExitCodeDiagnostic` is a type that wraps [`ExitCode`] to report it as a
+/// [`miette::Diagnostic`].
+///
+/// The diagnostic message is [`ExitCode::description`], and the diagnostic
+/// code is [`ExitCode::name`].
+///
+/// # Examples
+///
+/// ```
+/// # use miette::Diagnostic;
+/// # use sysexits::{ExitCode, ExitCodeDiagnostic};
+/// #
+/// let diagnostic = ExitCodeDiagnostic::new(ExitCode::Usage);
+/// assert_eq!(diagnostic.to_string(), "command line usage error");
+/// assert_eq!(diagnostic.code().unwrap().to_string(), "EX_USAGE");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ExitCodeDiagnostic(ExitCode);
+
+impl ExitCodeDiagnostic {
+    /// Creates a new `ExitCodeDiagnostic` from an `ExitCode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitCodeDiagnostic};
+    /// #
+    /// let diagnostic = ExitCodeDiagnostic::new(ExitCode::Usage);
+    /// assert_eq!(diagnostic.exit_code(), ExitCode::Usage);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(code: ExitCode) -> Self {
+        Self(code)
+    }
+
+    /// Returns the wrapped `ExitCode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitCodeDiagnostic};
+    /// #
+    /// let diagnostic = ExitCodeDiagnostic::new(ExitCode::Usage);
+    /// assert_eq!(diagnostic.exit_code(), ExitCode::Usage);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn exit_code(&self) -> ExitCode {
+        self.0
+    }
+}
+
+impl From<ExitCode> for ExitCodeDiagnostic {
+    /// Converts an `ExitCode` into an `ExitCodeDiagnostic`.
+    #[inline]
+    fn from(code: ExitCode) -> Self {
+        Self::new(code)
+    }
+}
+
+impl From<ExitCodeDiagnostic> for ExitCode {
+    /// Converts an `ExitCodeDiagnostic` into the wrapped `ExitCode`.
+    #[inline]
+    fn from(diagnostic: ExitCodeDiagnostic) -> Self {
+        diagnostic.exit_code()
+    }
+}
+
+impl fmt::Display for ExitCodeDiagnostic {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.exit_code().description())
+    }
+}
+
+impl core::error::Error for ExitCodeDiagnostic {}
+
+impl miette::Diagnostic for ExitCodeDiagnostic {
+    #[inline]
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(self.exit_code().name()))
+    }
+
+    #[inline]
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(std::format!(
+            "the process exits with status {}",
+            self.exit_code()
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::Diagnostic;
+
+    use super::*;
+
+    #[test]
+    fn exit_code() {
+        assert_eq!(
+            ExitCodeDiagnostic::new(ExitCode::Ok).exit_code(),
+            ExitCode::Ok
+        );
+        assert_eq!(
+            ExitCodeDiagnostic::new(ExitCode::Usage).exit_code(),
+            ExitCode::Usage
+        );
+    }
+
+    #[test]
+    const fn exit_code_is_const_fn() {
+        const _: ExitCode = ExitCodeDiagnostic::new(ExitCode::Ok).exit_code();
+    }
+
+    #[test]
+    fn from_exit_code_to_exit_code_diagnostic() {
+        assert_eq!(
+            ExitCodeDiagnostic::from(ExitCode::Usage),
+            ExitCodeDiagnostic::new(ExitCode::Usage)
+        );
+    }
+
+    #[test]
+    fn from_exit_code_diagnostic_to_exit_code() {
+        assert_eq!(
+            ExitCode::from(ExitCodeDiagnostic::new(ExitCode::Usage)),
+            ExitCode::Usage
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", ExitCodeDiagnostic::new(ExitCode::Ok)),
+            "successful termination"
+        );
+        assert_eq!(
+            format!("{}", ExitCodeDiagnostic::new(ExitCode::Usage)),
+            "command line usage error"
+        );
+    }
+
+    #[test]
+    fn diagnostic_code() {
+        assert_eq!(
+            format!(
+                "{}",
+                Diagnostic::code(&ExitCodeDiagnostic::new(ExitCode::Ok)).unwrap()
+            ),
+            "EX_OK"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Diagnostic::code(&ExitCodeDiagnostic::new(ExitCode::Usage)).unwrap()
+            ),
+            "EX_USAGE"
+        );
+    }
+
+    #[test]
+    fn diagnostic_help() {
+        assert_eq!(
+            format!("{}", ExitCodeDiagnostic::new(ExitCode::Ok).help().unwrap()),
+            "the process exits with status 0"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ExitCodeDiagnostic::new(ExitCode::Usage).help().unwrap()
+            ),
+            "the process exits with status 64"
+        );
+    }
+
+    #[test]
+    fn miette_report() {
+        let report = miette::Report::new(ExitCodeDiagnostic::new(ExitCode::DataErr));
+        assert_eq!(format!("{report}"), "data format error");
+        assert_eq!(format!("{}", report.code().unwrap()), "EX_DATAERR");
+    }
+}
@@ -0,0 +1,178 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Functions for terminating the current process with an [`ExitCode`].
+
+use super::{result::Result, ExitCode};
+
+/// Terminates the current process successfully.
+///
+/// This is a thin wrapper over [`ExitCode::exit`] with [`ExitCode::Ok`], and
+/// is the canonical way to terminate successfully.
+///
+/// # Examples
+///
+/// ```
+/// sysexits::exit_success();
+/// ```
+#[inline]
+#[track_caller]
+pub fn exit_success() -> ! {
+    ExitCode::Ok.exit()
+}
+
+/// Terminates the current process with the exit code `code`.
+///
+/// This is a thin wrapper over [`ExitCode::exit`], and is the canonical way to
+/// terminate unsuccessfully. Note that the current process terminates
+/// successfully if `code` is [`ExitCode::Ok`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use sysexits::ExitCode;
+/// #
+/// sysexits::exit_failure(ExitCode::Usage);
+/// ```
+#[inline]
+#[track_caller]
+pub fn exit_failure(code: ExitCode) -> ! {
+    code.exit()
+}
+
+/// An extension trait for terminating the current process on [`Result`]s.
+pub trait ResultExitExt<T> {
+    /// Returns the contained [`Ok`] value, or terminates the current process
+    /// with the `ExitCode` contained in the [`Err`] variant.
+    ///
+    /// Before terminating, this prints `Error: ` followed by the short
+    /// description of the `ExitCode` (see [`ExitCode::description`]) and a
+    /// newline to the standard error, e.g., `Error: data format error`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ResultExitExt};
+    /// #
+    /// let value = Ok::<_, ExitCode>(42).unwrap_or_exit();
+    /// assert_eq!(value, 42);
+    /// ```
+    fn unwrap_or_exit(self) -> T;
+}
+
+impl<T> ResultExitExt<T> for Result<T> {
+    #[inline]
+    #[track_caller]
+    fn unwrap_or_exit(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(code) => {
+                std::eprintln!("Error: {}", code.description());
+                code.exit()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process::Command, string::String};
+
+    use super::*;
+
+    const ENV_KEY: &str = "SYSEXITS_TEST_EXIT";
+
+    // Written to the standard error by the child process right before it
+    // terminates, so that a test which did not run in the child process is not
+    // regarded as passed.
+    const MARKER: &str = "sysexits: exiting";
+
+    fn run(test_name: &str, code: Option<ExitCode>) -> Option<i32> {
+        let mut command = Command::new(env::current_exe().unwrap());
+        command
+            .args(["--exact", "--nocapture", test_name])
+            .env(ENV_KEY, "1");
+        if let Some(code) = code {
+            command.env("SYSEXITS_TEST_EXIT_CODE", format!("{code}"));
+        }
+        let output = command.output().unwrap();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(
+            stderr.contains(MARKER),
+            "`{test_name}` did not run in the child process"
+        );
+        output.status.code()
+    }
+
+    #[test]
+    fn exit_success() {
+        if env::var_os(ENV_KEY).is_some() {
+            std::eprintln!("{MARKER}");
+            super::exit_success();
+        }
+
+        assert_eq!(run("exit_code::exit::tests::exit_success", None), Some(0));
+    }
+
+    #[test]
+    fn exit_failure() {
+        if env::var_os(ENV_KEY).is_some() {
+            let code = env::var("SYSEXITS_TEST_EXIT_CODE").unwrap();
+            std::eprintln!("{MARKER}");
+            super::exit_failure(code.parse().unwrap());
+        }
+
+        for code in [ExitCode::Usage, ExitCode::DataErr, ExitCode::Config] {
+            assert_eq!(
+                run("exit_code::exit::tests::exit_failure", Some(code)),
+                Some(code.to_i32())
+            );
+        }
+    }
+
+    #[test]
+    fn exit_functions_coerce_to_fn_pointers() {
+        // Adding `#[track_caller]` must not change the signatures, so these can
+        // still be coerced to function pointers.
+        let _: fn() -> ! = super::exit_success;
+        let _: fn(ExitCode) -> ! = super::exit_failure;
+        let _: fn(ExitCode) -> ! = ExitCode::exit;
+        let _: fn(Result<()>) -> () = ResultExitExt::unwrap_or_exit;
+    }
+
+    #[test]
+    fn unwrap_or_exit_when_ok() {
+        assert_eq!(Ok::<_, ExitCode>(42).unwrap_or_exit(), 42);
+        assert_eq!(Ok::<_, ExitCode>("foo").unwrap_or_exit(), "foo");
+        assert_eq!(Ok::<(), ExitCode>(()).unwrap_or_exit(), ());
+    }
+
+    #[test]
+    fn unwrap_or_exit_when_err() {
+        if env::var_os(ENV_KEY).is_some() {
+            let code: ExitCode = env::var("SYSEXITS_TEST_EXIT_CODE")
+                .unwrap()
+                .parse()
+                .unwrap();
+            Err::<(), _>(code).unwrap_or_exit();
+            unreachable!();
+        }
+
+        for code in [ExitCode::Usage, ExitCode::DataErr, ExitCode::Config] {
+            let output = Command::new(env::current_exe().unwrap())
+                .args([
+                    "--exact",
+                    "--nocapture",
+                    "exit_code::exit::tests::unwrap_or_exit_when_err",
+                ])
+                .env(ENV_KEY, "1")
+                .env("SYSEXITS_TEST_EXIT_CODE", format!("{code}"))
+                .output()
+                .unwrap();
+            assert_eq!(output.status.code(), Some(code.to_i32()));
+            let stderr = String::from_utf8(output.stderr).unwrap();
+            assert!(stderr.contains(&format!("Error: {}\n", code.description())));
+        }
+    }
+}
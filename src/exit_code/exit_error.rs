@@ -0,0 +1,444 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An error type which carries both an [`ExitCode`] and a message.
+
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::{boxed::Box, sync::Arc};
+use core::fmt;
+
+use super::ExitCode;
+
+/// `ExitError` is an error type which carries both an [`ExitCode`] and a
+/// human-readable message.
+///
+/// This is the richer companion to the plain [`ExitCode`]. The message is
+/// shown by [`Display`](fmt::Display), and the `ExitCode` is used when
+/// terminating the process. If the `std` feature is enabled, the underlying
+/// error can be attached with [`ExitError::with_source`], and is returned by
+/// [`Error::source`](core::error::Error::source).
+///
+/// The attached source is accepted as a `Box<dyn Error + Send + Sync>`, but is
+/// stored in an `Arc` so that `ExitError` can still implement [`Clone`]. A
+/// clone shares the same source.
+///
+/// [`PartialEq`] compares only the `ExitCode` and the message, so two errors
+/// with different sources are equal if they have the same `ExitCode` and
+/// message.
+///
+/// # Examples
+///
+/// ```
+/// # use sysexits::{ExitCode, ExitError};
+/// #
+/// fn parse(s: &str) -> Result<u8, ExitError> {
+///     s.parse()
+///         .map_err(|err| ExitError::new(ExitCode::DataErr, format!("invalid number: {err}")))
+/// }
+///
+/// let err = parse("a").unwrap_err();
+/// assert_eq!(err.code(), ExitCode::DataErr);
+/// assert_eq!(
+///     err.to_string(),
+///     "invalid number: invalid digit found in string"
+/// );
+/// ```
+#[derive(Clone, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ExitError {
+    code: ExitCode,
+    message: String,
+    #[cfg(feature = "std")]
+    source: Option<Arc<dyn core::error::Error + Send + Sync>>,
+}
+
+impl ExitError {
+    /// Creates a new `ExitError` from an `ExitCode` and a message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitError};
+    /// #
+    /// let err = ExitError::new(ExitCode::NoInput, "no such file");
+    /// assert_eq!(err.code(), ExitCode::NoInput);
+    /// assert_eq!(err.message(), "no such file");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(code: ExitCode, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self {
+            code,
+            message,
+            #[cfg(feature = "std")]
+            source: None,
+        }
+    }
+
+    /// Attaches `source` to this error as the underlying cause.
+    ///
+    /// The attached error is returned by
+    /// [`Error::source`](core::error::Error::source), so the error chain is
+    /// preserved up to the point where the process terminates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # use sysexits::{ExitCode, ExitError};
+    /// #
+    /// let source = "a".parse::<u8>().unwrap_err();
+    /// let err = ExitError::new(ExitCode::DataErr, "invalid number").with_source(source);
+    /// assert_eq!(
+    ///     err.source().unwrap().to_string(),
+    ///     "invalid digit found in string"
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn with_source(
+        mut self,
+        source: impl Into<Box<dyn core::error::Error + Send + Sync>>,
+    ) -> Self {
+        self.source = Some(Arc::from(source.into()));
+        self
+    }
+
+    /// Returns the `ExitCode` of this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitError};
+    /// #
+    /// let err = ExitError::new(ExitCode::NoInput, "no such file");
+    /// assert_eq!(err.code(), ExitCode::NoInput);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn code(&self) -> ExitCode {
+        self.code
+    }
+
+    /// Returns the message of this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitError};
+    /// #
+    /// let err = ExitError::new(ExitCode::NoInput, "no such file");
+    /// assert_eq!(err.message(), "no such file");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Prints the message of this error to the standard error and terminates
+    /// the current process with the `ExitCode` of this error.
+    ///
+    /// Each error in the source chain is printed on its own line after the
+    /// message, as `caused by: <source>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitError};
+    /// #
+    /// fn main() {
+    ///     ExitError::new(ExitCode::Ok, "nothing to do").exit();
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    #[track_caller]
+    pub fn exit(self) -> ! {
+        std::eprintln!("{}", self.report_message());
+        self.code.exit()
+    }
+
+    /// Returns the message of this error followed by the source chain, which
+    /// is printed by [`ExitError::exit`] and [`Termination::report`].
+    ///
+    /// [`Termination::report`]: std::process::Termination::report
+    #[cfg(feature = "std")]
+    fn report_message(&self) -> String {
+        use core::{error::Error, fmt::Write};
+
+        let mut message = self.message.clone();
+        let mut source = self.source();
+        while let Some(err) = source {
+            // Writing into a `String` never fails.
+            let _ = write!(message, "\ncaused by: {err}");
+            source = err.source();
+        }
+        message
+    }
+}
+
+impl fmt::Display for ExitError {
+    /// Shows the message of this error.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.message.fmt(f)
+    }
+}
+
+impl PartialEq for ExitError {
+    /// Compares the `ExitCode` and the message of two errors.
+    ///
+    /// The attached source is not compared.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code && self.message == other.message
+    }
+}
+
+impl Eq for ExitError {}
+
+impl core::error::Error for ExitError {
+    #[cfg(feature = "std")]
+    #[inline]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn core::error::Error + 'static))
+    }
+}
+
+impl From<ExitError> for ExitCode {
+    /// Converts an [`ExitError`] into its `ExitCode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitError};
+    /// #
+    /// let err = ExitError::new(ExitCode::NoInput, "no such file");
+    /// assert_eq!(ExitCode::from(err), ExitCode::NoInput);
+    /// ```
+    #[inline]
+    fn from(error: ExitError) -> Self {
+        error.code()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::process::Termination for ExitError {
+    /// Prints the message of this error to the standard error and returns the
+    /// `ExitCode` of this error.
+    ///
+    /// As with [`ExitError::exit`], the source chain is printed after the
+    /// message.
+    #[inline]
+    fn report(self) -> std::process::ExitCode {
+        std::eprintln!("{}", self.report_message());
+        self.code.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let err = ExitError::new(ExitCode::DataErr, "invalid data");
+        assert_eq!(err.code(), ExitCode::DataErr);
+        assert_eq!(err.message(), "invalid data");
+
+        let err = ExitError::new(ExitCode::Ok, String::new());
+        assert_eq!(err.code(), ExitCode::Ok);
+        assert_eq!(err.message(), "");
+    }
+
+    #[test]
+    fn clone() {
+        let err = ExitError::new(ExitCode::DataErr, "invalid data");
+        assert_eq!(err.clone(), err);
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", ExitError::new(ExitCode::DataErr, "invalid data")),
+            r#"ExitError { code: DataErr, message: "invalid data" }"#
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", ExitError::new(ExitCode::DataErr, "invalid data")),
+            r#"ExitError { code: DataErr, message: "invalid data", source: None }"#
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                ExitError::new(ExitCode::DataErr, "invalid data").with_source("bad byte")
+            ),
+            r#"ExitError { code: DataErr, message: "invalid data", source: Some("bad byte") }"#
+        );
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            ExitError::new(ExitCode::DataErr, "invalid data"),
+            ExitError::new(ExitCode::DataErr, "invalid data")
+        );
+        assert_ne!(
+            ExitError::new(ExitCode::DataErr, "invalid data"),
+            ExitError::new(ExitCode::Usage, "invalid data")
+        );
+        assert_ne!(
+            ExitError::new(ExitCode::DataErr, "invalid data"),
+            ExitError::new(ExitCode::DataErr, "invalid input")
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", ExitError::new(ExitCode::DataErr, "invalid data")),
+            "invalid data"
+        );
+        assert_eq!(
+            format!("{:>14}", ExitError::new(ExitCode::DataErr, "invalid data")),
+            "  invalid data"
+        );
+    }
+
+    #[test]
+    fn source() {
+        use core::error::Error;
+
+        assert!(ExitError::new(ExitCode::DataErr, "invalid data")
+            .source()
+            .is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_source() {
+        use core::error::Error;
+        use std::io;
+
+        let err = ExitError::new(ExitCode::NoInput, "cannot open input")
+            .with_source(io::Error::from(io::ErrorKind::NotFound));
+        assert_eq!(err.code(), ExitCode::NoInput);
+        assert_eq!(err.message(), "cannot open input");
+        let source = err.source().unwrap();
+        assert_eq!(
+            source.downcast_ref::<io::Error>().unwrap().kind(),
+            io::ErrorKind::NotFound
+        );
+        assert!(source.source().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_source_chain() {
+        use core::error::Error;
+        use std::string::ToString;
+
+        let inner = ExitError::new(ExitCode::DataErr, "invalid number")
+            .with_source("a".parse::<u8>().unwrap_err());
+        let outer = ExitError::new(ExitCode::DataErr, "invalid config").with_source(inner);
+        let inner = outer.source().unwrap();
+        let root = inner.source().unwrap();
+        assert_eq!(outer.to_string(), "invalid config");
+        assert_eq!(inner.to_string(), "invalid number");
+        assert_eq!(root.to_string(), "invalid digit found in string");
+        assert!(root.source().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn report_message() {
+        assert_eq!(
+            ExitError::new(ExitCode::DataErr, "invalid data").report_message(),
+            "invalid data"
+        );
+        assert_eq!(
+            ExitError::new(ExitCode::DataErr, "invalid data")
+                .with_source("bad byte")
+                .report_message(),
+            "invalid data\ncaused by: bad byte"
+        );
+
+        let inner = ExitError::new(ExitCode::DataErr, "invalid number")
+            .with_source("a".parse::<u8>().unwrap_err());
+        let outer = ExitError::new(ExitCode::DataErr, "invalid config").with_source(inner);
+        assert_eq!(
+            outer.report_message(),
+            "invalid config\ncaused by: invalid number\ncaused by: invalid digit found in \
+             string"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn clone_with_source() {
+        use core::error::Error;
+
+        let err = ExitError::new(ExitCode::DataErr, "invalid data").with_source("bad byte");
+        let cloned = err.clone();
+        assert_eq!(cloned, err);
+        assert_eq!(format!("{}", cloned.source().unwrap()), "bad byte");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn equality_ignores_source() {
+        assert_eq!(
+            ExitError::new(ExitCode::DataErr, "invalid data").with_source("bad byte"),
+            ExitError::new(ExitCode::DataErr, "invalid data")
+        );
+    }
+
+    #[test]
+    fn from_exit_error_to_exit_code() {
+        assert_eq!(
+            ExitCode::from(ExitError::new(ExitCode::DataErr, "invalid data")),
+            ExitCode::DataErr
+        );
+        assert_eq!(
+            ExitCode::from(ExitError::new(ExitCode::Ok, "")),
+            ExitCode::Ok
+        );
+    }
+
+    #[test]
+    fn question_mark() {
+        fn f() -> crate::Result<()> {
+            Err(ExitError::new(ExitCode::NoInput, "no such file"))?;
+            Ok(())
+        }
+
+        assert_eq!(f(), Err(ExitCode::NoInput));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn report() {
+        use std::process::Termination;
+
+        assert_eq!(
+            format!(
+                "{:?}",
+                ExitError::new(ExitCode::DataErr, "invalid data").report()
+            ),
+            format!("{:?}", std::process::ExitCode::from(65))
+        );
+    }
+}
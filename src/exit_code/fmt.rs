@@ -5,13 +5,187 @@
 
 //! Utilities for formatting and printing [`ExitCode`].
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 use core::fmt;
 
 use super::ExitCode;
 
+impl ExitCode {
+    /// Writes this `ExitCode` as a JSON value into `w`.
+    ///
+    /// If `named` is [`true`], writes the alias of the name (see
+    /// [`ExitCode::name`]) as a JSON string. Otherwise, writes the integer
+    /// representation as a JSON number.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if writing into `w` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let mut buf = String::new();
+    /// ExitCode::Usage.write_json(&mut buf, false).unwrap();
+    /// assert_eq!(buf, "64");
+    ///
+    /// let mut buf = String::new();
+    /// ExitCode::Usage.write_json(&mut buf, true).unwrap();
+    /// assert_eq!(buf, r#""EX_USAGE""#);
+    /// ```
+    #[inline]
+    pub fn write_json<W: fmt::Write>(self, w: &mut W, named: bool) -> fmt::Result {
+        if named {
+            write!(w, "\"{}\"", self.name())
+        } else {
+            write!(w, "{}", u8::from(self))
+        }
+    }
+
+    /// Writes the alias of the name of this `ExitCode` (see
+    /// [`ExitCode::name`]) into `w`.
+    ///
+    /// This does not allocate, so it can be used with fixed-size buffers in
+    /// `no_std` environments.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if writing into `w` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let mut buf = String::new();
+    /// ExitCode::Usage.write_name(&mut buf).unwrap();
+    /// assert_eq!(buf, "EX_USAGE");
+    /// ```
+    #[inline]
+    pub fn write_name<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(self.name())
+    }
+
+    /// Writes a report which explains this `ExitCode` into `w`.
+    ///
+    /// The report consists of two lines without a trailing newline. The first
+    /// line contains the alias of the name (see [`ExitCode::name`]), the
+    /// integer representation and the short description (see
+    /// [`ExitCode::description`]). The second line contains the canonical
+    /// name (see [`ExitCode::as_str`]). This is useful for `--explain`-style
+    /// help output.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if writing into `w` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let mut buf = String::new();
+    /// ExitCode::Usage.explain(&mut buf).unwrap();
+    /// assert_eq!(
+    ///     buf,
+    ///     "EX_USAGE (64): command line usage error\nvariant: ExitCode::Usage"
+    /// );
+    /// ```
+    #[inline]
+    pub fn explain<W: fmt::Write>(self, w: &mut W) -> fmt::Result {
+        writeln!(
+            w,
+            "{} ({}): {}",
+            self.name(),
+            u8::from(self),
+            self.description()
+        )?;
+        write!(w, "variant: ExitCode::{}", self.as_str())
+    }
+
+    /// Returns an object that implements [`Display`](fmt::Display) for showing
+    /// the integer representation of this `ExitCode` with a fixed width of 2.
+    ///
+    /// The value is zero-padded, so [`ExitCode::Ok`] is shown as `00`. This is
+    /// the same as formatting with `{:02}`, and is useful for aligned output
+    /// such as tables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(format!("{}", ExitCode::Ok.padded()), "00");
+    /// assert_eq!(format!("{}", ExitCode::Config.padded()), "78");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn padded(self) -> impl fmt::Display {
+        Padded(self)
+    }
+
+    /// Converts the integer representation of this `ExitCode` into a string in
+    /// the given `radix`.
+    ///
+    /// Digits greater than `9` are represented by lowercase letters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=16`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Usage.to_string_radix(2), "1000000");
+    /// assert_eq!(ExitCode::Usage.to_string_radix(10), "64");
+    /// assert_eq!(ExitCode::Config.to_string_radix(16), "4e");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn to_string_radix(self, radix: u32) -> String {
+        assert!(
+            (2..=16).contains(&radix),
+            "radix must be in the range `2..=16`, but is `{radix}`"
+        );
+
+        let mut value = u32::from(u8::from(self));
+        // `u8::MAX` in binary requires the most digits.
+        let mut digits = ['0'; u8::BITS as usize];
+        let mut len = 0;
+        loop {
+            digits[len] =
+                char::from_digit(value % radix, radix).expect("digit should be less than radix");
+            len += 1;
+            value /= radix;
+            if value == 0 {
+                break;
+            }
+        }
+        digits[..len].iter().rev().collect()
+    }
+}
+
+/// The return type of [`ExitCode::padded`].
+struct Padded(ExitCode);
+
+impl fmt::Display for Padded {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}", u8::from(self.0))
+    }
+}
+
 impl fmt::Display for ExitCode {
     /// Shows the integer representation of this `ExitCode`.
     ///
+    /// All formatting options such as width, alignment, fill, sign and
+    /// precision are applied in the same way as for [`u8`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -19,6 +193,7 @@ impl fmt::Display for ExitCode {
     /// #
     /// assert_eq!(format!("{}", ExitCode::Ok), "0");
     /// assert_eq!(format!("{}", ExitCode::Usage), "64");
+    /// assert_eq!(format!("{:>5}", ExitCode::Usage), "   64");
     /// ```
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -29,6 +204,8 @@ impl fmt::Display for ExitCode {
 impl fmt::Octal for ExitCode {
     /// Shows the integer representation of this `ExitCode`.
     ///
+    /// All formatting options are applied in the same way as for [`u8`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -46,6 +223,8 @@ impl fmt::Octal for ExitCode {
 impl fmt::LowerHex for ExitCode {
     /// Shows the integer representation of this `ExitCode`.
     ///
+    /// All formatting options are applied in the same way as for [`u8`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -63,6 +242,8 @@ impl fmt::LowerHex for ExitCode {
 impl fmt::UpperHex for ExitCode {
     /// Shows the integer representation of this `ExitCode`.
     ///
+    /// All formatting options are applied in the same way as for [`u8`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -80,6 +261,8 @@ impl fmt::UpperHex for ExitCode {
 impl fmt::Binary for ExitCode {
     /// Shows the integer representation of this `ExitCode`.
     ///
+    /// All formatting options are applied in the same way as for [`u8`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -97,6 +280,8 @@ impl fmt::Binary for ExitCode {
 impl fmt::LowerExp for ExitCode {
     /// Shows the integer representation of this `ExitCode`.
     ///
+    /// All formatting options are applied in the same way as for [`u8`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -114,6 +299,8 @@ impl fmt::LowerExp for ExitCode {
 impl fmt::UpperExp for ExitCode {
     /// Shows the integer representation of this `ExitCode`.
     ///
+    /// All formatting options are applied in the same way as for [`u8`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -130,8 +317,137 @@ impl fmt::UpperExp for ExitCode {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::String;
+
     use super::*;
 
+    struct FixedBuf<const N: usize> {
+        buf: [u8; N],
+        len: usize,
+    }
+
+    impl<const N: usize> FixedBuf<N> {
+        const fn new() -> Self {
+            Self {
+                buf: [0; N],
+                len: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.buf[..self.len]).unwrap()
+        }
+    }
+
+    impl<const N: usize> fmt::Write for FixedBuf<N> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len.checked_add(s.len()).ok_or(fmt::Error)?;
+            self.buf
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn write_json_as_number() {
+        let mut buf = String::new();
+        ExitCode::Ok.write_json(&mut buf, false).unwrap();
+        assert_eq!(buf, "0");
+
+        let mut buf = String::new();
+        ExitCode::Usage.write_json(&mut buf, false).unwrap();
+        assert_eq!(buf, "64");
+
+        let mut buf = String::new();
+        ExitCode::Config.write_json(&mut buf, false).unwrap();
+        assert_eq!(buf, "78");
+    }
+
+    #[test]
+    fn write_json_as_name() {
+        let mut buf = String::new();
+        ExitCode::Ok.write_json(&mut buf, true).unwrap();
+        assert_eq!(buf, r#""EX_OK""#);
+
+        let mut buf = String::new();
+        ExitCode::Usage.write_json(&mut buf, true).unwrap();
+        assert_eq!(buf, r#""EX_USAGE""#);
+
+        let mut buf = String::new();
+        ExitCode::Config.write_json(&mut buf, true).unwrap();
+        assert_eq!(buf, r#""EX_CONFIG""#);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string_radix() {
+        assert_eq!(ExitCode::Ok.to_string_radix(2), "0");
+        assert_eq!(ExitCode::Usage.to_string_radix(2), "1000000");
+        assert_eq!(ExitCode::DataErr.to_string_radix(2), "1000001");
+        assert_eq!(ExitCode::Config.to_string_radix(2), "1001110");
+
+        assert_eq!(ExitCode::Ok.to_string_radix(8), "0");
+        assert_eq!(ExitCode::Usage.to_string_radix(8), "100");
+        assert_eq!(ExitCode::DataErr.to_string_radix(8), "101");
+        assert_eq!(ExitCode::Config.to_string_radix(8), "116");
+
+        assert_eq!(ExitCode::Ok.to_string_radix(10), "0");
+        assert_eq!(ExitCode::Usage.to_string_radix(10), "64");
+        assert_eq!(ExitCode::DataErr.to_string_radix(10), "65");
+        assert_eq!(ExitCode::Config.to_string_radix(10), "78");
+
+        assert_eq!(ExitCode::Ok.to_string_radix(16), "0");
+        assert_eq!(ExitCode::Usage.to_string_radix(16), "40");
+        assert_eq!(ExitCode::DataErr.to_string_radix(16), "41");
+        assert_eq!(ExitCode::Config.to_string_radix(16), "4e");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string_radix_matches_fmt() {
+        for code in ExitCode::iter() {
+            assert_eq!(code.to_string_radix(2), format!("{code:b}"));
+            assert_eq!(code.to_string_radix(8), format!("{code:o}"));
+            assert_eq!(code.to_string_radix(10), format!("{code}"));
+            assert_eq!(code.to_string_radix(16), format!("{code:x}"));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string_radix_with_other_radix() {
+        assert_eq!(ExitCode::Usage.to_string_radix(3), "2101");
+        assert_eq!(ExitCode::Usage.to_string_radix(7), "121");
+        assert_eq!(ExitCode::Config.to_string_radix(12), "66");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "radix must be in the range `2..=16`, but is `1`")]
+    fn to_string_radix_with_too_small_radix() {
+        let _ = ExitCode::Usage.to_string_radix(1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "radix must be in the range `2..=16`, but is `17`")]
+    fn to_string_radix_with_too_large_radix() {
+        let _ = ExitCode::Usage.to_string_radix(17);
+    }
+
+    #[test]
+    fn write_json_appends() {
+        let mut buf = String::from("[");
+        ExitCode::Ok.write_json(&mut buf, false).unwrap();
+        buf.push(',');
+        ExitCode::DataErr.write_json(&mut buf, true).unwrap();
+        buf.push(']');
+        assert_eq!(buf, r#"[0,"EX_DATAERR"]"#);
+    }
+
     #[test]
     fn debug() {
         assert_eq!(format!("{:?}", ExitCode::Ok), "Ok");
@@ -172,6 +488,29 @@ mod tests {
         assert_eq!(format!("{}", ExitCode::Config), "78");
     }
 
+    #[test]
+    fn display_with_width() {
+        assert_eq!(format!("{:5}", ExitCode::Ok), "    0");
+        assert_eq!(format!("{:5}", ExitCode::Usage), "   64");
+        assert_eq!(format!("{:1}", ExitCode::Usage), "64");
+        assert_eq!(format!("{:width$}", ExitCode::Usage, width = 4), "  64");
+    }
+
+    #[test]
+    fn display_with_alignment() {
+        assert_eq!(format!("{:>5}", ExitCode::Usage), "   64");
+        assert_eq!(format!("{:<5}", ExitCode::Ok), "0    ");
+        assert_eq!(format!("{:^6}", ExitCode::Usage), "  64  ");
+    }
+
+    #[test]
+    fn display_with_fill() {
+        assert_eq!(format!("{:*>5}", ExitCode::Usage), "***64");
+        assert_eq!(format!("{:-<5}", ExitCode::Ok), "0----");
+        assert_eq!(format!("{:05}", ExitCode::Config), "00078");
+        assert_eq!(format!("{:+}", ExitCode::DataErr), "+65");
+    }
+
     #[test]
     fn octal() {
         assert_eq!(format!("{:o}", ExitCode::Ok), "0");
@@ -515,4 +854,266 @@ mod tests {
         assert_eq!(format!("{:E}", ExitCode::Config), "7.8E1");
         assert_eq!(format!("{:05E}", ExitCode::Config), "7.8E1");
     }
+
+    #[test]
+    fn display_forwards_flags() {
+        assert_eq!(format!("{:+}", ExitCode::Ok), "+0");
+        assert_eq!(format!("{:+}", ExitCode::Usage), "+64");
+        assert_eq!(format!("{:+05}", ExitCode::Usage), "+0064");
+        assert_eq!(format!("{:<+5}", ExitCode::Usage), "+64  ");
+        assert_eq!(format!("{:.3}", ExitCode::Usage), "64");
+        for code in ExitCode::iter() {
+            let value = u8::from(code);
+            assert_eq!(format!("{code}"), format!("{value}"));
+            assert_eq!(format!("{code:+}"), format!("{value:+}"));
+            assert_eq!(format!("{code:5}"), format!("{value:5}"));
+            assert_eq!(format!("{code:<5}"), format!("{value:<5}"));
+            assert_eq!(format!("{code:^5}"), format!("{value:^5}"));
+            assert_eq!(format!("{code:>5}"), format!("{value:>5}"));
+            assert_eq!(format!("{code:*^7}"), format!("{value:*^7}"));
+            assert_eq!(format!("{code:05}"), format!("{value:05}"));
+            assert_eq!(format!("{code:+05}"), format!("{value:+05}"));
+            assert_eq!(format!("{code:<+6}"), format!("{value:<+6}"));
+            assert_eq!(format!("{code:.0}"), format!("{value:.0}"));
+            assert_eq!(format!("{code:.3}"), format!("{value:.3}"));
+            assert_eq!(format!("{code:8.3}"), format!("{value:8.3}"));
+            assert_eq!(format!("{code:#}"), format!("{value:#}"));
+            assert_eq!(format!("{code:#05}"), format!("{value:#05}"));
+        }
+    }
+
+    #[test]
+    fn octal_forwards_flags() {
+        assert_eq!(format!("{:+o}", ExitCode::Usage), "+100");
+        assert_eq!(format!("{:#06o}", ExitCode::Usage), "0o0100");
+        assert_eq!(format!("{:.5o}", ExitCode::Usage), "100");
+        for code in ExitCode::iter() {
+            let value = u8::from(code);
+            assert_eq!(format!("{code:o}"), format!("{value:o}"));
+            assert_eq!(format!("{code:#o}"), format!("{value:#o}"));
+            assert_eq!(format!("{code:+o}"), format!("{value:+o}"));
+            assert_eq!(format!("{code:+#o}"), format!("{value:+#o}"));
+            assert_eq!(format!("{code:6o}"), format!("{value:6o}"));
+            assert_eq!(format!("{code:<6o}"), format!("{value:<6o}"));
+            assert_eq!(format!("{code:^6o}"), format!("{value:^6o}"));
+            assert_eq!(format!("{code:06o}"), format!("{value:06o}"));
+            assert_eq!(format!("{code:#08o}"), format!("{value:#08o}"));
+            assert_eq!(format!("{code:.5o}"), format!("{value:.5o}"));
+            assert_eq!(format!("{code:8.5o}"), format!("{value:8.5o}"));
+            assert_eq!(format!("{code:*>8o}"), format!("{value:*>8o}"));
+        }
+    }
+
+    #[test]
+    fn lower_hex_forwards_flags() {
+        assert_eq!(format!("{:+x}", ExitCode::Config), "+4e");
+        assert_eq!(format!("{:#06x}", ExitCode::Config), "0x004e");
+        for code in ExitCode::iter() {
+            let value = u8::from(code);
+            assert_eq!(format!("{code:x}"), format!("{value:x}"));
+            assert_eq!(format!("{code:#x}"), format!("{value:#x}"));
+            assert_eq!(format!("{code:+x}"), format!("{value:+x}"));
+            assert_eq!(format!("{code:+#x}"), format!("{value:+#x}"));
+            assert_eq!(format!("{code:6x}"), format!("{value:6x}"));
+            assert_eq!(format!("{code:<6x}"), format!("{value:<6x}"));
+            assert_eq!(format!("{code:^6x}"), format!("{value:^6x}"));
+            assert_eq!(format!("{code:06x}"), format!("{value:06x}"));
+            assert_eq!(format!("{code:#08x}"), format!("{value:#08x}"));
+            assert_eq!(format!("{code:.5x}"), format!("{value:.5x}"));
+            assert_eq!(format!("{code:8.5x}"), format!("{value:8.5x}"));
+            assert_eq!(format!("{code:*>8x}"), format!("{value:*>8x}"));
+        }
+    }
+
+    #[test]
+    fn upper_hex_forwards_flags() {
+        assert_eq!(format!("{:+X}", ExitCode::Config), "+4E");
+        assert_eq!(format!("{:#06X}", ExitCode::Config), "0x004E");
+        for code in ExitCode::iter() {
+            let value = u8::from(code);
+            assert_eq!(format!("{code:X}"), format!("{value:X}"));
+            assert_eq!(format!("{code:#X}"), format!("{value:#X}"));
+            assert_eq!(format!("{code:+X}"), format!("{value:+X}"));
+            assert_eq!(format!("{code:+#X}"), format!("{value:+#X}"));
+            assert_eq!(format!("{code:6X}"), format!("{value:6X}"));
+            assert_eq!(format!("{code:<6X}"), format!("{value:<6X}"));
+            assert_eq!(format!("{code:^6X}"), format!("{value:^6X}"));
+            assert_eq!(format!("{code:06X}"), format!("{value:06X}"));
+            assert_eq!(format!("{code:#08X}"), format!("{value:#08X}"));
+            assert_eq!(format!("{code:.5X}"), format!("{value:.5X}"));
+            assert_eq!(format!("{code:8.5X}"), format!("{value:8.5X}"));
+            assert_eq!(format!("{code:*>8X}"), format!("{value:*>8X}"));
+        }
+    }
+
+    #[test]
+    fn binary_forwards_flags() {
+        assert_eq!(format!("{:+b}", ExitCode::Usage), "+1000000");
+        assert_eq!(format!("{:#012b}", ExitCode::Usage), "0b0001000000");
+        assert_eq!(format!("{:.3b}", ExitCode::Usage), "1000000");
+        for code in ExitCode::iter() {
+            let value = u8::from(code);
+            assert_eq!(format!("{code:b}"), format!("{value:b}"));
+            assert_eq!(format!("{code:#b}"), format!("{value:#b}"));
+            assert_eq!(format!("{code:+b}"), format!("{value:+b}"));
+            assert_eq!(format!("{code:+#b}"), format!("{value:+#b}"));
+            assert_eq!(format!("{code:10b}"), format!("{value:10b}"));
+            assert_eq!(format!("{code:<10b}"), format!("{value:<10b}"));
+            assert_eq!(format!("{code:^10b}"), format!("{value:^10b}"));
+            assert_eq!(format!("{code:010b}"), format!("{value:010b}"));
+            assert_eq!(format!("{code:#012b}"), format!("{value:#012b}"));
+            assert_eq!(format!("{code:.3b}"), format!("{value:.3b}"));
+            assert_eq!(format!("{code:12.3b}"), format!("{value:12.3b}"));
+            assert_eq!(format!("{code:*>12b}"), format!("{value:*>12b}"));
+        }
+    }
+
+    #[test]
+    fn lower_exp_forwards_flags() {
+        assert_eq!(format!("{:+e}", ExitCode::Usage), "+6.4e1");
+        assert_eq!(format!("{:.3e}", ExitCode::Usage), "6.400e1");
+        assert_eq!(format!("{:010.2e}", ExitCode::Usage), "00006.40e1");
+        for code in ExitCode::iter() {
+            let value = u8::from(code);
+            assert_eq!(format!("{code:e}"), format!("{value:e}"));
+            assert_eq!(format!("{code:+e}"), format!("{value:+e}"));
+            assert_eq!(format!("{code:10e}"), format!("{value:10e}"));
+            assert_eq!(format!("{code:<10e}"), format!("{value:<10e}"));
+            assert_eq!(format!("{code:^10e}"), format!("{value:^10e}"));
+            assert_eq!(format!("{code:010e}"), format!("{value:010e}"));
+            assert_eq!(format!("{code:.0e}"), format!("{value:.0e}"));
+            assert_eq!(format!("{code:.3e}"), format!("{value:.3e}"));
+            assert_eq!(format!("{code:+.3e}"), format!("{value:+.3e}"));
+            assert_eq!(format!("{code:10.2e}"), format!("{value:10.2e}"));
+            assert_eq!(format!("{code:*>10e}"), format!("{value:*>10e}"));
+        }
+    }
+
+    #[test]
+    fn upper_exp_forwards_flags() {
+        assert_eq!(format!("{:+E}", ExitCode::Usage), "+6.4E1");
+        assert_eq!(format!("{:.3E}", ExitCode::Usage), "6.400E1");
+        for code in ExitCode::iter() {
+            let value = u8::from(code);
+            assert_eq!(format!("{code:E}"), format!("{value:E}"));
+            assert_eq!(format!("{code:+E}"), format!("{value:+E}"));
+            assert_eq!(format!("{code:10E}"), format!("{value:10E}"));
+            assert_eq!(format!("{code:<10E}"), format!("{value:<10E}"));
+            assert_eq!(format!("{code:^10E}"), format!("{value:^10E}"));
+            assert_eq!(format!("{code:010E}"), format!("{value:010E}"));
+            assert_eq!(format!("{code:.0E}"), format!("{value:.0E}"));
+            assert_eq!(format!("{code:.3E}"), format!("{value:.3E}"));
+            assert_eq!(format!("{code:+.3E}"), format!("{value:+.3E}"));
+            assert_eq!(format!("{code:10.2E}"), format!("{value:10.2E}"));
+            assert_eq!(format!("{code:*>10E}"), format!("{value:*>10E}"));
+        }
+    }
+
+    #[test]
+    fn write_name() {
+        let mut buf = String::new();
+        ExitCode::Ok.write_name(&mut buf).unwrap();
+        assert_eq!(buf, "EX_OK");
+
+        let mut buf = String::new();
+        ExitCode::Usage.write_name(&mut buf).unwrap();
+        assert_eq!(buf, "EX_USAGE");
+
+        let mut buf = String::new();
+        ExitCode::Config.write_name(&mut buf).unwrap();
+        assert_eq!(buf, "EX_CONFIG");
+    }
+
+    #[test]
+    fn write_name_matches_name() {
+        for code in ExitCode::iter() {
+            let mut buf = String::new();
+            code.write_name(&mut buf).unwrap();
+            assert_eq!(buf, code.name());
+        }
+    }
+
+    #[test]
+    fn write_name_to_fixed_buffer() {
+        let mut buf = FixedBuf::<16>::new();
+        ExitCode::Unavailable.write_name(&mut buf).unwrap();
+        assert_eq!(buf.as_str(), "EX_UNAVAILABLE");
+    }
+
+    #[test]
+    fn write_name_to_fixed_buffer_when_too_small() {
+        let mut buf = FixedBuf::<4>::new();
+        assert!(ExitCode::Usage.write_name(&mut buf).is_err());
+        assert_eq!(buf.as_str(), "");
+    }
+
+    #[test]
+    fn padded() {
+        assert_eq!(format!("{}", ExitCode::Ok.padded()), "00");
+        assert_eq!(format!("{}", ExitCode::Usage.padded()), "64");
+        assert_eq!(format!("{}", ExitCode::DataErr.padded()), "65");
+        assert_eq!(format!("{}", ExitCode::NoInput.padded()), "66");
+        assert_eq!(format!("{}", ExitCode::NoUser.padded()), "67");
+        assert_eq!(format!("{}", ExitCode::NoHost.padded()), "68");
+        assert_eq!(format!("{}", ExitCode::Unavailable.padded()), "69");
+        assert_eq!(format!("{}", ExitCode::Software.padded()), "70");
+        assert_eq!(format!("{}", ExitCode::OsErr.padded()), "71");
+        assert_eq!(format!("{}", ExitCode::OsFile.padded()), "72");
+        assert_eq!(format!("{}", ExitCode::CantCreat.padded()), "73");
+        assert_eq!(format!("{}", ExitCode::IoErr.padded()), "74");
+        assert_eq!(format!("{}", ExitCode::TempFail.padded()), "75");
+        assert_eq!(format!("{}", ExitCode::Protocol.padded()), "76");
+        assert_eq!(format!("{}", ExitCode::NoPerm.padded()), "77");
+        assert_eq!(format!("{}", ExitCode::Config.padded()), "78");
+    }
+
+    #[test]
+    fn padded_matches_zero_padded_format() {
+        for code in ExitCode::iter() {
+            assert_eq!(format!("{}", code.padded()), format!("{code:02}"));
+        }
+    }
+
+    #[test]
+    fn explain() {
+        let mut buf = String::new();
+        ExitCode::Ok.explain(&mut buf).unwrap();
+        assert_eq!(
+            buf,
+            "EX_OK (0): successful termination\nvariant: ExitCode::Ok"
+        );
+
+        let mut buf = String::new();
+        ExitCode::Usage.explain(&mut buf).unwrap();
+        assert_eq!(
+            buf,
+            "EX_USAGE (64): command line usage error\nvariant: ExitCode::Usage"
+        );
+
+        let mut buf = String::new();
+        ExitCode::Config.explain(&mut buf).unwrap();
+        assert_eq!(
+            buf,
+            "EX_CONFIG (78): configuration error\nvariant: ExitCode::Config"
+        );
+    }
+
+    #[test]
+    fn explain_contains_all_metadata() {
+        for code in ExitCode::iter() {
+            let mut buf = String::new();
+            code.explain(&mut buf).unwrap();
+            assert!(buf.contains(code.name()));
+            assert!(buf.contains(&format!("({code})")));
+            assert!(buf.contains(code.description()));
+            assert!(buf.contains(code.as_str()));
+            assert_eq!(buf.lines().count(), 2);
+        }
+    }
+
+    #[test]
+    fn explain_to_fixed_buffer_when_too_small() {
+        let mut buf = FixedBuf::<8>::new();
+        assert!(ExitCode::Usage.explain(&mut buf).is_err());
+    }
 }
@@ -0,0 +1,307 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A customizable conversion from [`io::ErrorKind`] into [`ExitCode`].
+
+use std::{io, vec::Vec};
+
+use super::ExitCode;
+
+/// `IoErrorMapper` is a type that converts an [`io::ErrorKind`] into an
+/// [`ExitCode`] with custom overrides.
+///
+/// By default, this converts in the same way as the [`From`] implementation
+/// for [`io::ErrorKind`]. Use [`IoErrorMapper::with`] to override the
+/// conversion of particular kinds.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// #
+/// # use sysexits::{ExitCode, IoErrorMapper};
+/// #
+/// let mapper = IoErrorMapper::new().with(io::ErrorKind::Unsupported, ExitCode::Unavailable);
+/// assert_eq!(
+///     mapper.map(io::ErrorKind::Unsupported),
+///     ExitCode::Unavailable
+/// );
+/// assert_eq!(mapper.map(io::ErrorKind::NotFound), ExitCode::NoInput);
+/// ```
+///
+/// Two `IoErrorMapper`s are equal if they convert every [`io::ErrorKind`] into
+/// the same `ExitCode`, regardless of the order of the overrides or whether an
+/// override is the same as the default conversion.
+///
+/// ```
+/// # use std::io;
+/// #
+/// # use sysexits::{ExitCode, IoErrorMapper};
+/// #
+/// assert_eq!(
+///     IoErrorMapper::new()
+///         .with(io::ErrorKind::Unsupported, ExitCode::Unavailable)
+///         .with(io::ErrorKind::BrokenPipe, ExitCode::Ok),
+///     IoErrorMapper::new()
+///         .with(io::ErrorKind::BrokenPipe, ExitCode::Ok)
+///         .with(io::ErrorKind::Unsupported, ExitCode::Unavailable)
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct IoErrorMapper {
+    overrides: Vec<(io::ErrorKind, ExitCode)>,
+}
+
+impl IoErrorMapper {
+    /// Creates a new `IoErrorMapper` without any overrides.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # use sysexits::{ExitCode, IoErrorMapper};
+    /// #
+    /// assert_eq!(
+    ///     IoErrorMapper::new().map(io::ErrorKind::NotFound),
+    ///     ExitCode::NoInput
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Overrides the conversion of `kind` with `code`.
+    ///
+    /// If `kind` has already been overridden, the previous override is
+    /// replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # use sysexits::{ExitCode, IoErrorMapper};
+    /// #
+    /// let mapper = IoErrorMapper::new()
+    ///     .with(io::ErrorKind::NotFound, ExitCode::CantCreat)
+    ///     .with(io::ErrorKind::NotFound, ExitCode::OsFile);
+    /// assert_eq!(mapper.map(io::ErrorKind::NotFound), ExitCode::OsFile);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with(mut self, kind: io::ErrorKind, code: ExitCode) -> Self {
+        match self.overrides.iter_mut().find(|(k, _)| *k == kind) {
+            Some((_, c)) => *c = code,
+            None => self.overrides.push((kind, code)),
+        }
+        self
+    }
+
+    /// Converts `kind` into an `ExitCode`.
+    ///
+    /// Returns the overridden `ExitCode` if `kind` has been overridden by
+    /// [`IoErrorMapper::with`], otherwise converts in the same way as the
+    /// [`From`] implementation for [`io::ErrorKind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # use sysexits::{ExitCode, IoErrorMapper};
+    /// #
+    /// let mapper = IoErrorMapper::new().with(io::ErrorKind::BrokenPipe, ExitCode::Ok);
+    /// assert_eq!(mapper.map(io::ErrorKind::BrokenPipe), ExitCode::Ok);
+    /// assert_eq!(mapper.map(io::ErrorKind::TimedOut), ExitCode::TempFail);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn map(&self, kind: io::ErrorKind) -> ExitCode {
+        self.overrides
+            .iter()
+            .find_map(|&(k, code)| (k == kind).then_some(code))
+            .unwrap_or_else(|| kind.into())
+    }
+
+    /// Converts the kind of `err` into an `ExitCode`.
+    ///
+    /// This is equivalent to calling [`IoErrorMapper::map`] with
+    /// [`io::Error::kind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # use sysexits::{ExitCode, IoErrorMapper};
+    /// #
+    /// let mapper = IoErrorMapper::new().with(io::ErrorKind::Other, ExitCode::Software);
+    /// assert_eq!(
+    ///     mapper.map_error(&io::Error::other("error")),
+    ///     ExitCode::Software
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn map_error(&self, err: &io::Error) -> ExitCode {
+        self.map(err.kind())
+    }
+}
+
+impl PartialEq for IoErrorMapper {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // Any kind which is not overridden by either is converted in the same
+        // way, so only the overridden kinds need to be compared.
+        self.overrides
+            .iter()
+            .chain(&other.overrides)
+            .all(|&(kind, _)| self.map(kind) == other.map(kind))
+    }
+}
+
+impl Eq for IoErrorMapper {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KINDS: [io::ErrorKind; 20] = [
+        io::ErrorKind::NotFound,
+        io::ErrorKind::PermissionDenied,
+        io::ErrorKind::ConnectionRefused,
+        io::ErrorKind::ConnectionReset,
+        io::ErrorKind::ConnectionAborted,
+        io::ErrorKind::NotConnected,
+        io::ErrorKind::AddrInUse,
+        io::ErrorKind::AddrNotAvailable,
+        io::ErrorKind::BrokenPipe,
+        io::ErrorKind::AlreadyExists,
+        io::ErrorKind::WouldBlock,
+        io::ErrorKind::InvalidInput,
+        io::ErrorKind::InvalidData,
+        io::ErrorKind::TimedOut,
+        io::ErrorKind::WriteZero,
+        io::ErrorKind::Interrupted,
+        io::ErrorKind::Unsupported,
+        io::ErrorKind::UnexpectedEof,
+        io::ErrorKind::OutOfMemory,
+        io::ErrorKind::Other,
+    ];
+
+    #[test]
+    fn default() {
+        assert_eq!(IoErrorMapper::default(), IoErrorMapper::new());
+    }
+
+    #[test]
+    fn map_without_overrides() {
+        let mapper = IoErrorMapper::new();
+        for kind in KINDS {
+            assert_eq!(mapper.map(kind), ExitCode::from(kind));
+        }
+    }
+
+    #[test]
+    fn map_with_overrides() {
+        let mapper = IoErrorMapper::new()
+            .with(io::ErrorKind::Unsupported, ExitCode::Unavailable)
+            .with(io::ErrorKind::BrokenPipe, ExitCode::Ok);
+        assert_eq!(
+            mapper.map(io::ErrorKind::Unsupported),
+            ExitCode::Unavailable
+        );
+        assert_eq!(mapper.map(io::ErrorKind::BrokenPipe), ExitCode::Ok);
+        for kind in KINDS
+            .into_iter()
+            .filter(|&kind| kind != io::ErrorKind::Unsupported && kind != io::ErrorKind::BrokenPipe)
+        {
+            assert_eq!(mapper.map(kind), ExitCode::from(kind));
+        }
+    }
+
+    #[test]
+    fn with_replaces_previous_override() {
+        let mapper = IoErrorMapper::new()
+            .with(io::ErrorKind::NotFound, ExitCode::CantCreat)
+            .with(io::ErrorKind::NotFound, ExitCode::OsFile);
+        assert_eq!(mapper.map(io::ErrorKind::NotFound), ExitCode::OsFile);
+        assert_eq!(
+            mapper,
+            IoErrorMapper::new().with(io::ErrorKind::NotFound, ExitCode::OsFile)
+        );
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(IoErrorMapper::new(), IoErrorMapper::new());
+        assert_eq!(
+            IoErrorMapper::new()
+                .with(io::ErrorKind::Unsupported, ExitCode::Unavailable)
+                .with(io::ErrorKind::BrokenPipe, ExitCode::Ok),
+            IoErrorMapper::new()
+                .with(io::ErrorKind::BrokenPipe, ExitCode::Ok)
+                .with(io::ErrorKind::Unsupported, ExitCode::Unavailable)
+        );
+        assert_eq!(
+            IoErrorMapper::new()
+                .with(io::ErrorKind::NotFound, ExitCode::CantCreat)
+                .with(io::ErrorKind::BrokenPipe, ExitCode::Ok)
+                .with(io::ErrorKind::NotFound, ExitCode::OsFile),
+            IoErrorMapper::new()
+                .with(io::ErrorKind::BrokenPipe, ExitCode::Ok)
+                .with(io::ErrorKind::NotFound, ExitCode::OsFile)
+        );
+    }
+
+    #[test]
+    fn equality_when_override_is_default() {
+        assert_eq!(
+            IoErrorMapper::new().with(io::ErrorKind::NotFound, ExitCode::NoInput),
+            IoErrorMapper::new()
+        );
+        assert_eq!(
+            IoErrorMapper::new(),
+            IoErrorMapper::new().with(io::ErrorKind::NotFound, ExitCode::NoInput)
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            IoErrorMapper::new().with(io::ErrorKind::NotFound, ExitCode::OsFile),
+            IoErrorMapper::new()
+        );
+        assert_ne!(
+            IoErrorMapper::new(),
+            IoErrorMapper::new().with(io::ErrorKind::NotFound, ExitCode::OsFile)
+        );
+        assert_ne!(
+            IoErrorMapper::new().with(io::ErrorKind::NotFound, ExitCode::OsFile),
+            IoErrorMapper::new().with(io::ErrorKind::NotFound, ExitCode::CantCreat)
+        );
+        assert_ne!(
+            IoErrorMapper::new().with(io::ErrorKind::NotFound, ExitCode::OsFile),
+            IoErrorMapper::new().with(io::ErrorKind::BrokenPipe, ExitCode::OsFile)
+        );
+    }
+
+    #[test]
+    fn map_error() {
+        let mapper = IoErrorMapper::new().with(io::ErrorKind::Other, ExitCode::Software);
+        assert_eq!(
+            mapper.map_error(&io::Error::other("error")),
+            ExitCode::Software
+        );
+        assert_eq!(
+            mapper.map_error(&io::Error::from(io::ErrorKind::NotFound)),
+            ExitCode::NoInput
+        );
+    }
+}
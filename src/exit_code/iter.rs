@@ -0,0 +1,575 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Utilities for aggregating multiple [`ExitCode`]s and [`Result`]s.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+use super::{result::Result, ExitCode};
+
+impl ExitCode {
+    /// Returns the most severe system exit code in `codes`.
+    ///
+    /// This compares [`ExitCode::severity`], not the integer representation,
+    /// so this is consistent with [`ExitCode::sort_by_severity`]. Any
+    /// unsuccessful termination is considered more severe than
+    /// [`ExitCode::Ok`].
+    ///
+    /// Returns [`ExitCode::Ok`] if `codes` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::worst([]), ExitCode::Ok);
+    /// assert_eq!(
+    ///     ExitCode::worst([ExitCode::Ok, ExitCode::IoErr, ExitCode::Usage]),
+    ///     ExitCode::IoErr
+    /// );
+    /// assert_eq!(
+    ///     ExitCode::worst([ExitCode::TempFail, ExitCode::Software]),
+    ///     ExitCode::Software
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn worst(codes: impl IntoIterator<Item = Self>) -> Self {
+        codes
+            .into_iter()
+            .max_by_key(|&code| code.severity())
+            .unwrap_or_default()
+    }
+
+    /// Returns the most severe error code in `results`.
+    ///
+    /// This is equivalent to [`ExitCode::worst`] over the [`Err`] values of
+    /// `results`, so [`ExitCode::Ok`] is returned if all items are [`Ok`] or
+    /// `results` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let results = [Ok(1), Err(ExitCode::DataErr), Err(ExitCode::IoErr)];
+    /// assert_eq!(ExitCode::aggregate(&results), ExitCode::IoErr);
+    ///
+    /// let results = [Ok::<_, ExitCode>(1), Ok(2)];
+    /// assert_eq!(ExitCode::aggregate(&results), ExitCode::Ok);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn aggregate<T>(results: &[Result<T>]) -> Self {
+        Self::worst(
+            results
+                .iter()
+                .filter_map(|result| result.as_ref().err().copied()),
+        )
+    }
+
+    /// Returns the first [`Err`] in `iter`, or [`Ok`] if all items are [`Ok`].
+    ///
+    /// This stops consuming `iter` at the first [`Err`], so this is the
+    /// short-circuiting counterpart to [`ExitCode::worst`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] with the `ExitCode` of the first [`Err`] in `iter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let results = [Ok(1), Err(ExitCode::DataErr), Err(ExitCode::IoErr)];
+    /// assert_eq!(ExitCode::first_failure(results), Err(ExitCode::DataErr));
+    ///
+    /// let results = [Ok::<_, ExitCode>(1), Ok(2)];
+    /// assert_eq!(ExitCode::first_failure(results), Ok(()));
+    /// ```
+    #[inline]
+    pub fn first_failure<I, T>(iter: I) -> Result<()>
+    where
+        I: IntoIterator<Item = Result<T>>,
+    {
+        iter.into_iter().try_for_each(|result| result.map(|_| ()))
+    }
+
+    /// Sorts `codes` in descending order of [`ExitCode::severity`].
+    ///
+    /// The most severe system exit code comes first. This sort does not
+    /// allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let mut codes = [ExitCode::Ok, ExitCode::Software, ExitCode::Usage];
+    /// ExitCode::sort_by_severity(&mut codes);
+    /// assert_eq!(codes, [ExitCode::Software, ExitCode::Usage, ExitCode::Ok]);
+    /// ```
+    #[inline]
+    pub fn sort_by_severity(codes: &mut [Self]) {
+        codes.sort_unstable_by_key(|code| Reverse(code.severity()));
+    }
+
+    /// Returns the distinct system exit codes in `codes`, preserving the order
+    /// in which they first appear.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(
+    ///     ExitCode::unique_codes([
+    ///         ExitCode::IoErr,
+    ///         ExitCode::Ok,
+    ///         ExitCode::IoErr,
+    ///         ExitCode::Usage,
+    ///         ExitCode::Ok
+    ///     ]),
+    ///     [ExitCode::IoErr, ExitCode::Ok, ExitCode::Usage]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn unique_codes(codes: impl IntoIterator<Item = Self>) -> Vec<Self> {
+        let mut seen = 0_u128;
+        codes
+            .into_iter()
+            .filter(|&code| {
+                let bit = 1 << code.to_u8();
+                let is_new = seen & bit == 0;
+                seen |= bit;
+                is_new
+            })
+            .collect()
+    }
+}
+
+impl FromIterator<Self> for ExitCode {
+    /// Collects system exit codes into the most severe one.
+    ///
+    /// This is equivalent to [`ExitCode::worst`], so an empty iterator yields
+    /// [`ExitCode::Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let code: ExitCode = [ExitCode::Ok, ExitCode::IoErr, ExitCode::Usage]
+    ///     .into_iter()
+    ///     .collect();
+    /// assert_eq!(code, ExitCode::IoErr);
+    /// ```
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        Self::worst(iter)
+    }
+}
+
+/// An extension trait for iterators over [`Result`]s.
+pub trait ResultIteratorExt {
+    /// Returns the `ExitCode` of the first [`Err`], or [`ExitCode::Ok`] if all
+    /// items are [`Ok`].
+    ///
+    /// This method stops consuming the iterator at the first [`Err`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ResultIteratorExt};
+    /// #
+    /// let results = [Ok(1), Err(ExitCode::DataErr), Err(ExitCode::IoErr)];
+    /// assert_eq!(results.into_iter().first_err_code(), ExitCode::DataErr);
+    ///
+    /// let results = [Ok::<_, ExitCode>(1), Ok(2)];
+    /// assert_eq!(results.into_iter().first_err_code(), ExitCode::Ok);
+    /// ```
+    fn first_err_code(self) -> ExitCode;
+}
+
+impl<T, I: Iterator<Item = Result<T>>> ResultIteratorExt for I {
+    #[inline]
+    fn first_err_code(mut self) -> ExitCode {
+        self.find_map(core::result::Result::err).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::iter;
+
+    use super::*;
+
+    #[test]
+    fn sort_by_severity() {
+        let mut codes = [
+            ExitCode::Ok,
+            ExitCode::Usage,
+            ExitCode::DataErr,
+            ExitCode::NoInput,
+            ExitCode::NoUser,
+            ExitCode::NoHost,
+            ExitCode::Unavailable,
+            ExitCode::Software,
+            ExitCode::OsErr,
+            ExitCode::OsFile,
+            ExitCode::CantCreat,
+            ExitCode::IoErr,
+            ExitCode::TempFail,
+            ExitCode::Protocol,
+            ExitCode::NoPerm,
+            ExitCode::Config,
+        ];
+        ExitCode::sort_by_severity(&mut codes);
+        assert_eq!(
+            codes,
+            [
+                ExitCode::Software,
+                ExitCode::OsErr,
+                ExitCode::OsFile,
+                ExitCode::IoErr,
+                ExitCode::CantCreat,
+                ExitCode::Protocol,
+                ExitCode::Unavailable,
+                ExitCode::Config,
+                ExitCode::NoPerm,
+                ExitCode::NoHost,
+                ExitCode::NoUser,
+                ExitCode::NoInput,
+                ExitCode::DataErr,
+                ExitCode::Usage,
+                ExitCode::TempFail,
+                ExitCode::Ok
+            ]
+        );
+    }
+
+    #[test]
+    fn sort_by_severity_with_duplicates() {
+        let mut codes = [
+            ExitCode::Ok,
+            ExitCode::IoErr,
+            ExitCode::Ok,
+            ExitCode::Usage,
+            ExitCode::IoErr,
+        ];
+        ExitCode::sort_by_severity(&mut codes);
+        assert_eq!(
+            codes,
+            [
+                ExitCode::IoErr,
+                ExitCode::IoErr,
+                ExitCode::Usage,
+                ExitCode::Ok,
+                ExitCode::Ok
+            ]
+        );
+    }
+
+    #[test]
+    fn sort_by_severity_when_empty() {
+        let mut codes: [ExitCode; 0] = [];
+        ExitCode::sort_by_severity(&mut codes);
+        assert!(codes.is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unique_codes() {
+        assert_eq!(
+            ExitCode::unique_codes([
+                ExitCode::DataErr,
+                ExitCode::Ok,
+                ExitCode::DataErr,
+                ExitCode::Config,
+                ExitCode::Ok,
+                ExitCode::Usage,
+                ExitCode::Config
+            ]),
+            [
+                ExitCode::DataErr,
+                ExitCode::Ok,
+                ExitCode::Config,
+                ExitCode::Usage
+            ]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unique_codes_when_all_distinct() {
+        assert_eq!(
+            ExitCode::unique_codes(ExitCode::iter().rev()),
+            ExitCode::iter().rev().collect::<Vec<_>>()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unique_codes_when_all_same() {
+        assert_eq!(
+            ExitCode::unique_codes(iter::repeat(ExitCode::Software).take(10)),
+            [ExitCode::Software]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unique_codes_when_empty() {
+        assert!(ExitCode::unique_codes(iter::empty()).is_empty());
+    }
+
+    #[test]
+    fn worst_when_empty() {
+        assert_eq!(ExitCode::worst(iter::empty()), ExitCode::Ok);
+    }
+
+    #[test]
+    fn worst_when_all_ok() {
+        assert_eq!(ExitCode::worst([ExitCode::Ok]), ExitCode::Ok);
+        assert_eq!(
+            ExitCode::worst([ExitCode::Ok, ExitCode::Ok, ExitCode::Ok]),
+            ExitCode::Ok
+        );
+    }
+
+    #[test]
+    fn worst_when_mixed() {
+        assert_eq!(
+            ExitCode::worst([ExitCode::Ok, ExitCode::Usage]),
+            ExitCode::Usage
+        );
+        assert_eq!(
+            ExitCode::worst([ExitCode::Usage, ExitCode::Ok]),
+            ExitCode::Usage
+        );
+        assert_eq!(
+            ExitCode::worst([
+                ExitCode::DataErr,
+                ExitCode::Ok,
+                ExitCode::Config,
+                ExitCode::Usage
+            ]),
+            ExitCode::Config
+        );
+        assert_eq!(
+            ExitCode::worst([ExitCode::TempFail, ExitCode::TempFail, ExitCode::Ok]),
+            ExitCode::TempFail
+        );
+    }
+
+    #[test]
+    fn worst_by_severity() {
+        assert_eq!(
+            ExitCode::worst([ExitCode::TempFail, ExitCode::Software]),
+            ExitCode::Software
+        );
+        assert_eq!(
+            ExitCode::worst([ExitCode::Software, ExitCode::TempFail]),
+            ExitCode::Software
+        );
+        assert_eq!(
+            ExitCode::worst([ExitCode::Usage, ExitCode::TempFail]),
+            ExitCode::Usage
+        );
+        assert_eq!(
+            ExitCode::worst([ExitCode::Config, ExitCode::OsErr, ExitCode::IoErr]),
+            ExitCode::OsErr
+        );
+    }
+
+    #[test]
+    fn aggregate_when_empty() {
+        assert_eq!(ExitCode::aggregate::<()>(&[]), ExitCode::Ok);
+    }
+
+    #[test]
+    fn aggregate_when_all_ok() {
+        assert_eq!(
+            ExitCode::aggregate::<u8>(&[Ok(1), Ok(2), Ok(3)]),
+            ExitCode::Ok
+        );
+    }
+
+    #[test]
+    fn aggregate_when_mixed() {
+        assert_eq!(
+            ExitCode::aggregate(&[Ok(1), Err(ExitCode::Usage), Ok(3)]),
+            ExitCode::Usage
+        );
+        assert_eq!(
+            ExitCode::aggregate(&[
+                Err(ExitCode::DataErr),
+                Ok(2),
+                Err(ExitCode::Config),
+                Err(ExitCode::Usage)
+            ]),
+            ExitCode::Config
+        );
+        let results: Vec<Result<u8>> =
+            vec![Err(ExitCode::TempFail), Ok(2), Err(ExitCode::TempFail)];
+        assert_eq!(ExitCode::aggregate(&results), ExitCode::TempFail);
+    }
+
+    #[test]
+    fn aggregate_by_severity() {
+        assert_eq!(
+            ExitCode::aggregate::<()>(&[Err(ExitCode::TempFail), Err(ExitCode::Software)]),
+            ExitCode::Software
+        );
+        assert_eq!(
+            ExitCode::aggregate(&[Err(ExitCode::Software), Ok(2), Err(ExitCode::TempFail)]),
+            ExitCode::Software
+        );
+    }
+
+    #[test]
+    fn first_failure_when_empty() {
+        assert_eq!(ExitCode::first_failure(iter::empty::<Result<()>>()), Ok(()));
+    }
+
+    #[test]
+    fn first_failure_when_all_ok() {
+        assert_eq!(
+            ExitCode::first_failure::<_, u8>([Ok(1), Ok(2), Ok(3)]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn first_failure_when_err() {
+        assert_eq!(
+            ExitCode::first_failure([Ok(1), Err(ExitCode::NoInput), Ok(3)]),
+            Err(ExitCode::NoInput)
+        );
+        assert_eq!(
+            ExitCode::first_failure([Ok(1), Err(ExitCode::DataErr), Err(ExitCode::Config), Ok(4)]),
+            Err(ExitCode::DataErr)
+        );
+    }
+
+    #[test]
+    fn first_failure_stops_at_first_err() {
+        let mut results = vec![Ok(1), Err(ExitCode::IoErr), Ok(3)].into_iter();
+        assert_eq!(
+            ExitCode::first_failure(results.by_ref()),
+            Err(ExitCode::IoErr)
+        );
+        assert_eq!(results.next(), Some(Ok(3)));
+    }
+
+    #[test]
+    fn from_iter_when_empty() {
+        assert_eq!(iter::empty().collect::<ExitCode>(), ExitCode::Ok);
+    }
+
+    #[test]
+    fn from_iter_when_all_ok() {
+        assert_eq!(iter::once(ExitCode::Ok).collect::<ExitCode>(), ExitCode::Ok);
+        assert_eq!(
+            iter::repeat(ExitCode::Ok).take(3).collect::<ExitCode>(),
+            ExitCode::Ok
+        );
+    }
+
+    #[test]
+    fn from_iter_when_mixed() {
+        assert_eq!(
+            [ExitCode::Ok, ExitCode::Usage]
+                .into_iter()
+                .collect::<ExitCode>(),
+            ExitCode::Usage
+        );
+        assert_eq!(
+            [
+                ExitCode::DataErr,
+                ExitCode::Ok,
+                ExitCode::Config,
+                ExitCode::Usage
+            ]
+            .into_iter()
+            .collect::<ExitCode>(),
+            ExitCode::Config
+        );
+        assert_eq!(
+            vec![Ok(1), Err(ExitCode::NoInput), Err(ExitCode::IoErr)]
+                .into_iter()
+                .map(ExitCode::from)
+                .collect::<ExitCode>(),
+            ExitCode::IoErr
+        );
+    }
+
+    #[test]
+    fn from_iter_matches_worst() {
+        let codes = [ExitCode::TempFail, ExitCode::Ok, ExitCode::Software];
+        assert_eq!(codes.into_iter().collect::<ExitCode>(), ExitCode::Software);
+        assert_eq!(
+            codes.into_iter().collect::<ExitCode>(),
+            ExitCode::worst(codes)
+        );
+    }
+
+    #[test]
+    fn from_iter_by_severity() {
+        assert_eq!(
+            [ExitCode::TempFail, ExitCode::Software]
+                .into_iter()
+                .collect::<ExitCode>(),
+            ExitCode::Software
+        );
+        assert_eq!(
+            [ExitCode::Software, ExitCode::TempFail]
+                .into_iter()
+                .collect::<ExitCode>(),
+            ExitCode::Software
+        );
+        assert_eq!(
+            [ExitCode::TempFail, ExitCode::Usage]
+                .into_iter()
+                .collect::<ExitCode>(),
+            ExitCode::Usage
+        );
+    }
+
+    #[test]
+    fn first_err_code_when_empty() {
+        assert_eq!(iter::empty::<Result<()>>().first_err_code(), ExitCode::Ok);
+    }
+
+    #[test]
+    fn first_err_code_when_all_ok() {
+        let results: Vec<Result<u8>> = vec![Ok(1), Ok(2), Ok(3)];
+        assert_eq!(results.into_iter().first_err_code(), ExitCode::Ok);
+    }
+
+    #[test]
+    fn first_err_code_when_err() {
+        let results: Vec<Result<u8>> = vec![Err(ExitCode::Usage)];
+        assert_eq!(results.into_iter().first_err_code(), ExitCode::Usage);
+
+        let results = vec![Ok(1), Err(ExitCode::NoInput), Ok(3)];
+        assert_eq!(results.into_iter().first_err_code(), ExitCode::NoInput);
+
+        let results = vec![Ok(1), Err(ExitCode::DataErr), Err(ExitCode::Config), Ok(4)];
+        assert_eq!(results.into_iter().first_err_code(), ExitCode::DataErr);
+    }
+
+    #[test]
+    fn first_err_code_stops_at_first_err() {
+        let mut results = vec![Ok(1), Err(ExitCode::IoErr), Ok(3)].into_iter();
+        assert_eq!(results.by_ref().first_err_code(), ExitCode::IoErr);
+        assert_eq!(results.next(), Some(Ok(3)));
+    }
+}
@@ -0,0 +1,141 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`Termination`] wrapper for [`Result`].
+
+use std::process::{self, Termination};
+
+use super::{result::Result, ExitCode};
+
+/// `MainResult` is a wrapper of [`Result<T>`] which can be returned from the
+/// `main` function.
+///
+/// The standard library already implements [`Termination`] for
+/// [`Result<T, E>`](core::result::Result) where `E` implements [`Debug`], but
+/// that implementation prints the error with [`Debug`] and always reports
+/// [`process::ExitCode::FAILURE`]. Since [`Result<T>`] is a type alias of a
+/// foreign type, implementing [`Termination`] for it directly is not possible
+/// due to the orphan rules, so this newtype is provided instead.
+///
+/// If the result is [`Ok`], this reports the contained value. Otherwise, this
+/// reports the `ExitCode` contained in the [`Err`] variant without printing
+/// anything.
+///
+/// # Examples
+///
+/// ```
+/// # use sysexits::{ExitCode, MainResult, Result};
+/// #
+/// fn run(input: &str) -> Result<()> {
+///     let count: u8 = input.parse().map_err(|_| ExitCode::DataErr)?;
+///     assert_eq!(count, 42);
+///     Ok(())
+/// }
+///
+/// fn main() -> MainResult {
+///     run("42").into()
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MainResult<T = ()>(pub Result<T>);
+
+impl<T> MainResult<T> {
+    /// Returns the contained [`Result<T>`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the contained result is [`Err`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, MainResult};
+    /// #
+    /// assert_eq!(
+    ///     MainResult::<()>::from(Err(ExitCode::Usage)).into_inner(),
+    ///     Err(ExitCode::Usage)
+    /// );
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> Result<T> {
+        self.0
+    }
+}
+
+impl<T> From<Result<T>> for MainResult<T> {
+    /// Converts a [`Result<T>`] into a `MainResult`.
+    #[inline]
+    fn from(result: Result<T>) -> Self {
+        Self(result)
+    }
+}
+
+impl<T> From<MainResult<T>> for Result<T> {
+    /// Converts a `MainResult` into the contained [`Result<T>`].
+    #[inline]
+    fn from(result: MainResult<T>) -> Self {
+        result.into_inner()
+    }
+}
+
+impl<T: Termination> Termination for MainResult<T> {
+    #[inline]
+    fn report(self) -> process::ExitCode {
+        match self.0 {
+            Ok(value) => value.report(),
+            Err(code) => ExitCode::report(code),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_inner() {
+        assert_eq!(MainResult::from(Ok(42)).into_inner(), Ok(42));
+        assert_eq!(
+            MainResult::<()>::from(Err(ExitCode::Usage)).into_inner(),
+            Err(ExitCode::Usage)
+        );
+    }
+
+    #[test]
+    fn from_main_result_to_result() {
+        assert_eq!(Result::from(MainResult(Ok(()))), Ok(()));
+        assert_eq!(
+            Result::<()>::from(MainResult(Err(ExitCode::Config))),
+            Err(ExitCode::Config)
+        );
+    }
+
+    #[test]
+    fn report_when_ok() {
+        assert_eq!(
+            format!("{:?}", MainResult(Ok(())).report()),
+            format!("{:?}", process::ExitCode::SUCCESS)
+        );
+        assert_eq!(
+            format!("{:?}", MainResult(Ok(ExitCode::Usage)).report()),
+            format!("{:?}", process::ExitCode::from(64))
+        );
+    }
+
+    #[test]
+    fn report_when_err() {
+        assert_eq!(
+            format!("{:?}", MainResult::<()>(Err(ExitCode::Usage)).report()),
+            format!("{:?}", process::ExitCode::from(64))
+        );
+        assert_eq!(
+            format!("{:?}", MainResult::<()>(Err(ExitCode::DataErr)).report()),
+            format!("{:?}", process::ExitCode::from(65))
+        );
+        assert_eq!(
+            format!("{:?}", MainResult::<()>(Err(ExitCode::Config)).report()),
+            format!("{:?}", process::ExitCode::from(78))
+        );
+    }
+}
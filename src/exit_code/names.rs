@@ -0,0 +1,547 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Names of [`ExitCode`] and iteration over all variants.
+//!
+//! This module is the single source of truth for the names of [`ExitCode`].
+//! [`ExitCode::VARIANTS`] and [`ExitCode::iter`] list every variant, and
+//! [`ExitCode::as_str`], [`ExitCode::name`], [`ExitCode::from_name`] and the
+//! [`FromStr`] implementation convert between variants and names.
+//!
+//! # Canonical name and alias
+//!
+//! The canonical name of a variant is its variant name (e.g., `DataErr`),
+//! which is returned by [`ExitCode::as_str`] and is the same as the output of
+//! [`Debug`](core::fmt::Debug). The name of the macro defined in
+//! [`<sysexits.h>`] (e.g., `EX_DATAERR`) is an alias of it, which is returned
+//! by [`ExitCode::name`].
+//!
+//! Parsing accepts both spellings. The following APIs output the canonical
+//! name:
+//!
+//! - [`ExitCode::as_str`]
+//! - [`ExitCodeDescriptor::name`](crate::ExitCodeDescriptor::name)
+//! - [`Summary`](crate::Summary)
+//! - `serde_name::serialize` (with the `serde` feature)
+//!
+//! The following APIs output the alias:
+//!
+//! - [`ExitCode::name`]
+//! - [`ExitCodeDescriptor::macro_name`](crate::ExitCodeDescriptor::macro_name)
+//! - [`ExitCode::write_name`]
+//! - [`ExitCode::write_json`] when `named` is `true`
+//! - The first line of [`ExitCode::explain`]
+//! - The `code_name` field recorded by `ExitCode::record` (with the `tracing`
+//!   feature)
+//!
+//! # Round-trip guarantees
+//!
+//! For every variant `code`:
+//!
+//! - `ExitCode::from_name(code.as_str())` and
+//!   `ExitCode::from_name(code.name())` return `Some(code)`.
+//! - `code.as_str().parse()` and `code.name().parse()` return `Ok(code)`.
+//! - `ExitCode::try_from(u8::from(code))` returns `Ok(code)`.
+//!
+//! [`<sysexits.h>`]: https://man.openbsd.org/sysexits
+
+use core::{iter::Copied, slice, str::FromStr};
+
+use super::ExitCode;
+use crate::error::ParseExitCodeError;
+
+impl ExitCode {
+    /// All variants of `ExitCode` in ascending order of value.
+    ///
+    /// This is a slice rather than an array so that adding a variant is not a
+    /// breaking change of its type. Every variant appears exactly once, and
+    /// its names can be parsed back with [`ExitCode::from_name`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::VARIANTS.len(), 16);
+    /// assert_eq!(ExitCode::VARIANTS.first(), Some(&ExitCode::Ok));
+    /// assert_eq!(ExitCode::VARIANTS.last(), Some(&ExitCode::Config));
+    ///
+    /// for &code in ExitCode::VARIANTS {
+    ///     assert_eq!(ExitCode::from_name(code.as_str()), Some(code));
+    ///     assert_eq!(ExitCode::from_name(code.name()), Some(code));
+    /// }
+    /// ```
+    pub const VARIANTS: &'static [Self] = &[
+        Self::Ok,
+        Self::Usage,
+        Self::DataErr,
+        Self::NoInput,
+        Self::NoUser,
+        Self::NoHost,
+        Self::Unavailable,
+        Self::Software,
+        Self::OsErr,
+        Self::OsFile,
+        Self::CantCreat,
+        Self::IoErr,
+        Self::TempFail,
+        Self::Protocol,
+        Self::NoPerm,
+        Self::Config,
+    ];
+
+    /// Returns an iterator over all variants of `ExitCode` in ascending order
+    /// of value.
+    ///
+    /// This yields the same items as [`ExitCode::VARIANTS`], so every variant
+    /// is yielded exactly once. Each item round-trips through its integer
+    /// representation and through its names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let mut iter = ExitCode::iter();
+    /// assert_eq!(iter.next(), Some(ExitCode::Ok));
+    /// assert_eq!(iter.next(), Some(ExitCode::Usage));
+    /// assert_eq!(iter.next_back(), Some(ExitCode::Config));
+    ///
+    /// for code in ExitCode::iter() {
+    ///     assert_eq!(ExitCode::try_from(u8::from(code)), Ok(code));
+    ///     assert_eq!(code.as_str().parse(), Ok(code));
+    ///     assert_eq!(code.name().parse(), Ok(code));
+    /// }
+    /// ```
+    #[inline]
+    pub fn iter() -> Copied<slice::Iter<'static, Self>> {
+        Self::VARIANTS.iter().copied()
+    }
+
+    /// Returns an iterator over all variants of `ExitCode` which indicate
+    /// unsuccessful termination, in ascending order of value.
+    ///
+    /// This yields the same items as [`ExitCode::iter`] except
+    /// [`ExitCode::Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let mut failures = ExitCode::failures();
+    /// assert_eq!(failures.len(), 15);
+    /// assert_eq!(failures.next(), Some(ExitCode::Usage));
+    /// assert_eq!(failures.next_back(), Some(ExitCode::Config));
+    /// ```
+    #[inline]
+    pub fn failures() -> Copied<slice::Iter<'static, Self>> {
+        // `ExitCode::Ok` is always the first variant.
+        Self::VARIANTS[1..].iter().copied()
+    }
+
+    /// Returns the canonical name of this system exit code.
+    ///
+    /// The canonical name is the variant name. Use [`ExitCode::name`] to get
+    /// its alias, the macro name defined in [`<sysexits.h>`], instead.
+    ///
+    /// The returned name can always be parsed back into the same system exit
+    /// code with [`ExitCode::from_name`] or [`str::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.as_str(), "Ok");
+    /// assert_eq!(ExitCode::DataErr.as_str(), "DataErr");
+    ///
+    /// assert_eq!(
+    ///     ExitCode::from_name(ExitCode::DataErr.as_str()),
+    ///     Some(ExitCode::DataErr)
+    /// );
+    /// ```
+    ///
+    /// [`<sysexits.h>`]: https://man.openbsd.org/sysexits
+    #[must_use]
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "Ok",
+            Self::Usage => "Usage",
+            Self::DataErr => "DataErr",
+            Self::NoInput => "NoInput",
+            Self::NoUser => "NoUser",
+            Self::NoHost => "NoHost",
+            Self::Unavailable => "Unavailable",
+            Self::Software => "Software",
+            Self::OsErr => "OsErr",
+            Self::OsFile => "OsFile",
+            Self::CantCreat => "CantCreat",
+            Self::IoErr => "IoErr",
+            Self::TempFail => "TempFail",
+            Self::Protocol => "Protocol",
+            Self::NoPerm => "NoPerm",
+            Self::Config => "Config",
+        }
+    }
+
+    /// Returns the name of the macro defined in [`<sysexits.h>`] which
+    /// corresponds to this system exit code.
+    ///
+    /// This is an alias of the canonical name returned by
+    /// [`ExitCode::as_str`]. It can be parsed back in the same way with
+    /// [`ExitCode::from_name`] or [`str::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.name(), "EX_OK");
+    /// assert_eq!(ExitCode::Usage.name(), "EX_USAGE");
+    /// ```
+    ///
+    /// [`<sysexits.h>`]: https://man.openbsd.org/sysexits
+    #[must_use]
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Ok => "EX_OK",
+            Self::Usage => "EX_USAGE",
+            Self::DataErr => "EX_DATAERR",
+            Self::NoInput => "EX_NOINPUT",
+            Self::NoUser => "EX_NOUSER",
+            Self::NoHost => "EX_NOHOST",
+            Self::Unavailable => "EX_UNAVAILABLE",
+            Self::Software => "EX_SOFTWARE",
+            Self::OsErr => "EX_OSERR",
+            Self::OsFile => "EX_OSFILE",
+            Self::CantCreat => "EX_CANTCREAT",
+            Self::IoErr => "EX_IOERR",
+            Self::TempFail => "EX_TEMPFAIL",
+            Self::Protocol => "EX_PROTOCOL",
+            Self::NoPerm => "EX_NOPERM",
+            Self::Config => "EX_CONFIG",
+        }
+    }
+
+    /// Returns the system exit code with the canonical name or the alias
+    /// `name`.
+    ///
+    /// This is the inverse of both [`ExitCode::as_str`] and
+    /// [`ExitCode::name`]: for every system exit code `code`,
+    /// `ExitCode::from_name(code.as_str())` and
+    /// `ExitCode::from_name(code.name())` return `Some(code)`. Matching is
+    /// case-sensitive.
+    ///
+    /// Returns [`None`] if `name` is not a known name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::from_name("DataErr"), Some(ExitCode::DataErr));
+    /// assert_eq!(ExitCode::from_name("EX_DATAERR"), Some(ExitCode::DataErr));
+    /// assert_eq!(ExitCode::from_name("dataerr"), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::iter().find(|code| code.as_str() == name || code.name() == name)
+    }
+}
+
+impl FromStr for ExitCode {
+    type Err = ParseExitCodeError;
+
+    /// Parses a string `s` into an `ExitCode`.
+    ///
+    /// This is equivalent to the [`TryFrom<&str>`](TryFrom) implementation,
+    /// so `s` can be a decimal integer, a canonical name or an alias.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `s` is neither a valid exit code number nor a known
+    /// name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!("65".parse(), Ok(ExitCode::DataErr));
+    /// assert_eq!("DataErr".parse(), Ok(ExitCode::DataErr));
+    /// assert_eq!("EX_DATAERR".parse(), Ok(ExitCode::DataErr));
+    ///
+    /// assert!("dataerr".parse::<ExitCode>().is_err());
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    use super::*;
+    use crate::error::ParseExitCodeErrorKind;
+
+    #[test]
+    fn variants() {
+        assert_eq!(
+            ExitCode::VARIANTS,
+            [
+                ExitCode::Ok,
+                ExitCode::Usage,
+                ExitCode::DataErr,
+                ExitCode::NoInput,
+                ExitCode::NoUser,
+                ExitCode::NoHost,
+                ExitCode::Unavailable,
+                ExitCode::Software,
+                ExitCode::OsErr,
+                ExitCode::OsFile,
+                ExitCode::CantCreat,
+                ExitCode::IoErr,
+                ExitCode::TempFail,
+                ExitCode::Protocol,
+                ExitCode::NoPerm,
+                ExitCode::Config
+            ]
+        );
+    }
+
+    #[test]
+    fn variants_are_sorted_and_unique() {
+        assert!(ExitCode::VARIANTS
+            .windows(2)
+            .all(|w| u8::from(w[0]) < u8::from(w[1])));
+    }
+
+    #[test]
+    fn iter() {
+        assert_eq!(ExitCode::iter().collect::<Vec<_>>(), ExitCode::VARIANTS);
+        assert_eq!(ExitCode::iter().len(), 16);
+        assert_eq!(
+            ExitCode::iter().rev().collect::<Vec<_>>(),
+            ExitCode::VARIANTS.iter().rev().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn failures() {
+        assert_eq!(
+            ExitCode::failures().collect::<Vec<_>>(),
+            [
+                ExitCode::Usage,
+                ExitCode::DataErr,
+                ExitCode::NoInput,
+                ExitCode::NoUser,
+                ExitCode::NoHost,
+                ExitCode::Unavailable,
+                ExitCode::Software,
+                ExitCode::OsErr,
+                ExitCode::OsFile,
+                ExitCode::CantCreat,
+                ExitCode::IoErr,
+                ExitCode::TempFail,
+                ExitCode::Protocol,
+                ExitCode::NoPerm,
+                ExitCode::Config
+            ]
+        );
+        assert_eq!(ExitCode::failures().count(), 15);
+        assert_eq!(ExitCode::failures().next(), Some(ExitCode::Usage));
+        assert_eq!(ExitCode::failures().last(), Some(ExitCode::Config));
+    }
+
+    #[test]
+    fn failures_are_all_failure() {
+        assert!(ExitCode::failures().all(|code| code.is_failure()));
+        assert_eq!(
+            ExitCode::failures().collect::<Vec<_>>(),
+            ExitCode::iter()
+                .filter(ExitCode::is_failure)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn as_str() {
+        assert_eq!(ExitCode::Ok.as_str(), "Ok");
+        assert_eq!(ExitCode::Usage.as_str(), "Usage");
+        assert_eq!(ExitCode::DataErr.as_str(), "DataErr");
+        assert_eq!(ExitCode::NoInput.as_str(), "NoInput");
+        assert_eq!(ExitCode::NoUser.as_str(), "NoUser");
+        assert_eq!(ExitCode::NoHost.as_str(), "NoHost");
+        assert_eq!(ExitCode::Unavailable.as_str(), "Unavailable");
+        assert_eq!(ExitCode::Software.as_str(), "Software");
+        assert_eq!(ExitCode::OsErr.as_str(), "OsErr");
+        assert_eq!(ExitCode::OsFile.as_str(), "OsFile");
+        assert_eq!(ExitCode::CantCreat.as_str(), "CantCreat");
+        assert_eq!(ExitCode::IoErr.as_str(), "IoErr");
+        assert_eq!(ExitCode::TempFail.as_str(), "TempFail");
+        assert_eq!(ExitCode::Protocol.as_str(), "Protocol");
+        assert_eq!(ExitCode::NoPerm.as_str(), "NoPerm");
+        assert_eq!(ExitCode::Config.as_str(), "Config");
+    }
+
+    #[test]
+    fn as_str_matches_debug() {
+        for code in ExitCode::iter() {
+            assert_eq!(code.as_str(), format!("{code:?}"));
+        }
+    }
+
+    #[test]
+    const fn as_str_is_const_fn() {
+        const _: &str = ExitCode::Ok.as_str();
+    }
+
+    #[test]
+    fn name() {
+        assert_eq!(ExitCode::Ok.name(), "EX_OK");
+        assert_eq!(ExitCode::Usage.name(), "EX_USAGE");
+        assert_eq!(ExitCode::DataErr.name(), "EX_DATAERR");
+        assert_eq!(ExitCode::NoInput.name(), "EX_NOINPUT");
+        assert_eq!(ExitCode::NoUser.name(), "EX_NOUSER");
+        assert_eq!(ExitCode::NoHost.name(), "EX_NOHOST");
+        assert_eq!(ExitCode::Unavailable.name(), "EX_UNAVAILABLE");
+        assert_eq!(ExitCode::Software.name(), "EX_SOFTWARE");
+        assert_eq!(ExitCode::OsErr.name(), "EX_OSERR");
+        assert_eq!(ExitCode::OsFile.name(), "EX_OSFILE");
+        assert_eq!(ExitCode::CantCreat.name(), "EX_CANTCREAT");
+        assert_eq!(ExitCode::IoErr.name(), "EX_IOERR");
+        assert_eq!(ExitCode::TempFail.name(), "EX_TEMPFAIL");
+        assert_eq!(ExitCode::Protocol.name(), "EX_PROTOCOL");
+        assert_eq!(ExitCode::NoPerm.name(), "EX_NOPERM");
+        assert_eq!(ExitCode::Config.name(), "EX_CONFIG");
+    }
+
+    #[test]
+    const fn name_is_const_fn() {
+        const _: &str = ExitCode::Ok.name();
+    }
+
+    #[test]
+    fn from_name_when_unknown() {
+        assert_eq!(ExitCode::from_name(""), None);
+        assert_eq!(ExitCode::from_name("0"), None);
+        assert_eq!(ExitCode::from_name("dataerr"), None);
+        assert_eq!(ExitCode::from_name("ex_dataerr"), None);
+        assert_eq!(ExitCode::from_name("DataErr "), None);
+    }
+
+    #[test]
+    fn from_str_when_invalid() {
+        assert_eq!(
+            "".parse::<ExitCode>().unwrap_err().kind(),
+            ParseExitCodeErrorKind::NotANumber
+        );
+        assert_eq!(
+            "79".parse::<ExitCode>().unwrap_err().kind(),
+            ParseExitCodeErrorKind::OutOfRange
+        );
+        assert_eq!(
+            "dataerr".parse::<ExitCode>().unwrap_err().kind(),
+            ParseExitCodeErrorKind::UnknownName
+        );
+        assert_eq!(
+            "6x".parse::<ExitCode>().unwrap_err().kind(),
+            ParseExitCodeErrorKind::NotANumber
+        );
+    }
+
+    // The number, the canonical name and the alias of every variant, in the
+    // order of `ExitCode::VARIANTS`.
+    const NAMES: [(ExitCode, u8, &str, &str); 16] = [
+        (ExitCode::Ok, 0, "Ok", "EX_OK"),
+        (ExitCode::Usage, 64, "Usage", "EX_USAGE"),
+        (ExitCode::DataErr, 65, "DataErr", "EX_DATAERR"),
+        (ExitCode::NoInput, 66, "NoInput", "EX_NOINPUT"),
+        (ExitCode::NoUser, 67, "NoUser", "EX_NOUSER"),
+        (ExitCode::NoHost, 68, "NoHost", "EX_NOHOST"),
+        (ExitCode::Unavailable, 69, "Unavailable", "EX_UNAVAILABLE"),
+        (ExitCode::Software, 70, "Software", "EX_SOFTWARE"),
+        (ExitCode::OsErr, 71, "OsErr", "EX_OSERR"),
+        (ExitCode::OsFile, 72, "OsFile", "EX_OSFILE"),
+        (ExitCode::CantCreat, 73, "CantCreat", "EX_CANTCREAT"),
+        (ExitCode::IoErr, 74, "IoErr", "EX_IOERR"),
+        (ExitCode::TempFail, 75, "TempFail", "EX_TEMPFAIL"),
+        (ExitCode::Protocol, 76, "Protocol", "EX_PROTOCOL"),
+        (ExitCode::NoPerm, 77, "NoPerm", "EX_NOPERM"),
+        (ExitCode::Config, 78, "Config", "EX_CONFIG"),
+    ];
+
+    #[test]
+    fn every_name_api_matches_names() {
+        assert_eq!(ExitCode::VARIANTS, NAMES.map(|(code, ..)| code).as_slice());
+        assert!(ExitCode::iter().eq(NAMES.map(|(code, ..)| code)));
+        for (code, number, canonical, alias) in NAMES {
+            // number
+            assert_eq!(u8::from(code), number);
+            assert_eq!(ExitCode::try_from(number), Ok(code));
+            assert_eq!(format!("{code}"), number.to_string());
+            assert_eq!(format!("{code}").parse(), Ok(code));
+
+            // canonical name
+            assert_eq!(code.as_str(), canonical);
+            assert_eq!(format!("{code:?}"), canonical);
+            assert_eq!(code.to_descriptor().name, canonical);
+            assert_eq!(
+                format!("{}", crate::Summary(&[code])),
+                format!("{canonical}×1")
+            );
+            assert_eq!(ExitCode::from_name(canonical), Some(code));
+            assert_eq!(canonical.parse(), Ok(code));
+            assert_eq!(ExitCode::try_from(canonical), Ok(code));
+
+            // alias
+            assert_eq!(code.name(), alias);
+            assert_eq!(code.to_descriptor().macro_name, alias);
+            let mut buf = String::new();
+            code.write_name(&mut buf).unwrap();
+            assert_eq!(buf, alias);
+            let mut buf = String::new();
+            code.write_json(&mut buf, true).unwrap();
+            assert_eq!(buf, format!("\"{alias}\""));
+            let mut buf = String::new();
+            code.explain(&mut buf).unwrap();
+            assert!(buf.starts_with(&format!("{alias} ({number}): ")));
+            assert!(buf.ends_with(&format!("variant: ExitCode::{canonical}")));
+            assert_eq!(ExitCode::from_name(alias), Some(code));
+            assert_eq!(alias.parse(), Ok(code));
+            assert_eq!(ExitCode::try_from(alias), Ok(code));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_name_matches_names() {
+        #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        struct Named(#[serde(with = "crate::serde_name")] ExitCode);
+
+        for (code, _, canonical, alias) in NAMES {
+            assert_eq!(
+                serde_json::to_string(&Named(code)).unwrap(),
+                format!("\"{canonical}\"")
+            );
+            assert_eq!(
+                serde_json::from_str::<Named>(&format!("\"{canonical}\"")).unwrap(),
+                Named(code)
+            );
+            assert_eq!(
+                serde_json::from_str::<Named>(&format!("\"{alias}\"")).unwrap(),
+                Named(code)
+            );
+        }
+    }
+}
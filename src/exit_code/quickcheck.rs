@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Implementations of [`quickcheck`] traits for [`ExitCode`].
+
+use std::boxed::Box;
+
+use quickcheck::{Arbitrary, Gen};
+
+use super::ExitCode;
+
+impl Arbitrary for ExitCode {
+    /// Generates an `ExitCode` uniformly from all variants.
+    #[inline]
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(Self::VARIANTS)
+            .expect("`ExitCode::VARIANTS` should not be empty")
+    }
+
+    /// Shrinks this `ExitCode` toward [`ExitCode::Ok`].
+    ///
+    /// The candidates are the variants whose integer representation is less
+    /// than that of this `ExitCode`, starting with [`ExitCode::Ok`]. So
+    /// [`ExitCode::Ok`] is not shrunk any further.
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let code = *self;
+        Box::new(Self::iter().take_while(move |&c| c.to_u8() < code.to_u8()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use quickcheck::{QuickCheck, TestResult};
+
+    use super::*;
+
+    #[test]
+    fn arbitrary() {
+        let mut g = Gen::new(16);
+        let mut seen = Vec::new();
+        for _ in 0..1000 {
+            let code = ExitCode::arbitrary(&mut g);
+            if !seen.contains(&code) {
+                seen.push(code);
+            }
+        }
+        assert_eq!(seen.len(), ExitCode::VARIANTS.len());
+    }
+
+    #[test]
+    fn shrink() {
+        assert_eq!(ExitCode::Ok.shrink().count(), 0);
+        assert!(ExitCode::Usage.shrink().eq([ExitCode::Ok]));
+        assert!(ExitCode::NoInput
+            .shrink()
+            .eq([ExitCode::Ok, ExitCode::Usage, ExitCode::DataErr]));
+        assert_eq!(ExitCode::Config.shrink().count(), 15);
+    }
+
+    #[test]
+    fn shrink_starts_with_ok() {
+        for code in ExitCode::failures() {
+            assert_eq!(code.shrink().next(), Some(ExitCode::Ok));
+        }
+    }
+
+    #[test]
+    fn shrink_eventually_reaches_ok() {
+        for code in ExitCode::iter() {
+            // Always take the least simplified candidate, which is the slowest
+            // path to `ExitCode::Ok`.
+            let mut current = code;
+            while let Some(next) = current.shrink().last() {
+                assert!(next.to_u8() < current.to_u8());
+                current = next;
+            }
+            assert_eq!(current, ExitCode::Ok);
+        }
+    }
+
+    #[test]
+    fn quickcheck() {
+        fn prop(code: ExitCode) -> TestResult {
+            TestResult::from_bool(ExitCode::try_from(code.to_u8()) == Ok(code))
+        }
+
+        QuickCheck::new().quickcheck(prop as fn(ExitCode) -> TestResult);
+    }
+}
@@ -0,0 +1,262 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`Termination`] wrapper which remembers the original [`ExitCode`].
+
+use std::process::{self, Termination};
+
+use super::ExitCode;
+
+/// `Reported` is a type that pairs a [`process::ExitCode`] with the
+/// [`ExitCode`] it was created from.
+///
+/// [`process::ExitCode`] is opaque, so the original `ExitCode` cannot be
+/// recovered from it. `Reported` keeps both, which allows a lossless round-trip
+/// while still being usable as the return type of the `main` function.
+///
+/// # Examples
+///
+/// ```
+/// # use sysexits::{ExitCode, Reported};
+/// #
+/// let reported = ExitCode::DataErr.reported();
+/// assert_eq!(reported.code(), ExitCode::DataErr);
+/// assert_eq!(ExitCode::from(reported), ExitCode::DataErr);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Reported {
+    code: ExitCode,
+    process_exit_code: process::ExitCode,
+}
+
+impl Reported {
+    /// Creates a new `Reported` from an `ExitCode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, Reported};
+    /// #
+    /// let reported = Reported::new(ExitCode::Usage);
+    /// assert_eq!(reported.code(), ExitCode::Usage);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(code: ExitCode) -> Self {
+        let process_exit_code = code.report();
+        Self {
+            code,
+            process_exit_code,
+        }
+    }
+
+    /// Returns the original `ExitCode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, Reported};
+    /// #
+    /// assert_eq!(Reported::new(ExitCode::Ok).code(), ExitCode::Ok);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn code(&self) -> ExitCode {
+        self.code
+    }
+
+    /// Returns the [`process::ExitCode`] reported by the original `ExitCode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, Reported};
+    /// #
+    /// assert_eq!(
+    ///     format!("{:?}", Reported::new(ExitCode::Usage).process_exit_code()),
+    ///     format!("{:?}", std::process::ExitCode::from(64))
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn process_exit_code(&self) -> process::ExitCode {
+        self.process_exit_code
+    }
+}
+
+/// Converts `code` into a [`process::ExitCode`].
+///
+/// The result is the same as the [`process::ExitCode`] kept by
+/// [`Reported::new`], so this and [`Reported::code`] together give a lossless
+/// round-trip. Use [`Reported`] instead if the original `ExitCode` needs to be
+/// kept alongside.
+///
+/// # Examples
+///
+/// ```
+/// # use sysexits::ExitCode;
+/// #
+/// assert_eq!(
+///     format!("{:?}", sysexits::from_reported(ExitCode::Usage)),
+///     format!("{:?}", std::process::ExitCode::from(64))
+/// );
+/// ```
+#[must_use]
+#[inline]
+pub fn from_reported(code: ExitCode) -> process::ExitCode {
+    Reported::new(code).process_exit_code()
+}
+
+impl ExitCode {
+    /// Converts this `ExitCode` into a [`Reported`], which keeps this
+    /// `ExitCode` alongside the corresponding [`process::ExitCode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Usage.reported().code(), ExitCode::Usage);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn reported(self) -> Reported {
+        Reported::new(self)
+    }
+}
+
+impl From<ExitCode> for Reported {
+    /// Converts an `ExitCode` into a `Reported`.
+    #[inline]
+    fn from(code: ExitCode) -> Self {
+        Self::new(code)
+    }
+}
+
+impl From<Reported> for ExitCode {
+    /// Converts a `Reported` into the original `ExitCode`.
+    #[inline]
+    fn from(reported: Reported) -> Self {
+        reported.code()
+    }
+}
+
+impl From<Reported> for process::ExitCode {
+    /// Converts a `Reported` into a [`process::ExitCode`].
+    #[inline]
+    fn from(reported: Reported) -> Self {
+        reported.process_exit_code()
+    }
+}
+
+impl Termination for Reported {
+    #[inline]
+    fn report(self) -> process::ExitCode {
+        self.process_exit_code()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn code() {
+        assert_eq!(Reported::new(ExitCode::Ok).code(), ExitCode::Ok);
+        assert_eq!(Reported::new(ExitCode::Usage).code(), ExitCode::Usage);
+        assert_eq!(Reported::new(ExitCode::DataErr).code(), ExitCode::DataErr);
+        assert_eq!(Reported::new(ExitCode::NoInput).code(), ExitCode::NoInput);
+        assert_eq!(Reported::new(ExitCode::NoUser).code(), ExitCode::NoUser);
+        assert_eq!(Reported::new(ExitCode::NoHost).code(), ExitCode::NoHost);
+        assert_eq!(
+            Reported::new(ExitCode::Unavailable).code(),
+            ExitCode::Unavailable
+        );
+        assert_eq!(Reported::new(ExitCode::Software).code(), ExitCode::Software);
+        assert_eq!(Reported::new(ExitCode::OsErr).code(), ExitCode::OsErr);
+        assert_eq!(Reported::new(ExitCode::OsFile).code(), ExitCode::OsFile);
+        assert_eq!(
+            Reported::new(ExitCode::CantCreat).code(),
+            ExitCode::CantCreat
+        );
+        assert_eq!(Reported::new(ExitCode::IoErr).code(), ExitCode::IoErr);
+        assert_eq!(Reported::new(ExitCode::TempFail).code(), ExitCode::TempFail);
+        assert_eq!(Reported::new(ExitCode::Protocol).code(), ExitCode::Protocol);
+        assert_eq!(Reported::new(ExitCode::NoPerm).code(), ExitCode::NoPerm);
+        assert_eq!(Reported::new(ExitCode::Config).code(), ExitCode::Config);
+    }
+
+    #[test]
+    fn process_exit_code() {
+        assert_eq!(
+            format!("{:?}", Reported::new(ExitCode::Ok).process_exit_code()),
+            format!("{:?}", process::ExitCode::from(0))
+        );
+        assert_eq!(
+            format!("{:?}", Reported::new(ExitCode::Usage).process_exit_code()),
+            format!("{:?}", process::ExitCode::from(64))
+        );
+        assert_eq!(
+            format!("{:?}", Reported::new(ExitCode::Config).process_exit_code()),
+            format!("{:?}", process::ExitCode::from(78))
+        );
+    }
+
+    #[test]
+    fn from_reported() {
+        for code in ExitCode::iter() {
+            assert_eq!(
+                format!("{:?}", super::from_reported(code)),
+                format!("{:?}", process::ExitCode::from(u8::from(code)))
+            );
+            assert_eq!(
+                format!("{:?}", super::from_reported(code)),
+                format!("{:?}", code.reported().process_exit_code())
+            );
+        }
+    }
+
+    #[test]
+    fn reported() {
+        assert_eq!(ExitCode::Ok.reported().code(), ExitCode::Ok);
+        assert_eq!(ExitCode::Usage.reported().code(), ExitCode::Usage);
+        assert_eq!(ExitCode::Config.reported().code(), ExitCode::Config);
+    }
+
+    #[test]
+    fn from_exit_code_to_reported() {
+        assert_eq!(Reported::from(ExitCode::Ok).code(), ExitCode::Ok);
+        assert_eq!(Reported::from(ExitCode::Usage).code(), ExitCode::Usage);
+    }
+
+    #[test]
+    fn from_reported_to_exit_code() {
+        assert_eq!(ExitCode::from(ExitCode::Ok.reported()), ExitCode::Ok);
+        assert_eq!(ExitCode::from(ExitCode::Usage.reported()), ExitCode::Usage);
+    }
+
+    #[test]
+    fn from_reported_to_process_exit_code() {
+        assert_eq!(
+            format!("{:?}", process::ExitCode::from(ExitCode::Ok.reported())),
+            format!("{:?}", process::ExitCode::from(0))
+        );
+        assert_eq!(
+            format!("{:?}", process::ExitCode::from(ExitCode::Usage.reported())),
+            format!("{:?}", process::ExitCode::from(64))
+        );
+    }
+
+    #[test]
+    fn report() {
+        assert_eq!(
+            format!("{:?}", ExitCode::Ok.reported().report()),
+            format!("{:?}", process::ExitCode::from(0))
+        );
+        assert_eq!(
+            format!("{:?}", ExitCode::Usage.reported().report()),
+            format!("{:?}", process::ExitCode::from(64))
+        );
+    }
+}
@@ -11,6 +11,44 @@ use super::ExitCode;
 ///
 /// In case of an error, an appropriate variant of [`ExitCode`] can describe the
 /// exact cause in further detail.
+///
+/// Since the standard library's implementation of the `?` operator on
+/// [`Result`](core::result::Result) converts the error type with [`From`],
+/// `?` converts any error type which implements [`Into<ExitCode>`] into an
+/// early return of the corresponding `ExitCode` on stable Rust. This does not
+/// require the unstable `try_trait_v2` feature, and a `Try`/`FromResidual`
+/// implementation for this type is not possible outside of `core` due to the
+/// orphan rules.
+///
+/// # Examples
+///
+/// ```
+/// # use sysexits::{ExitCode, Result};
+/// #
+/// struct InvalidInput;
+///
+/// impl From<InvalidInput> for ExitCode {
+///     fn from(_: InvalidInput) -> Self {
+///         Self::DataErr
+///     }
+/// }
+///
+/// fn validate(s: &str) -> core::result::Result<&str, InvalidInput> {
+///     if s.is_empty() {
+///         Err(InvalidInput)
+///     } else {
+///         Ok(s)
+///     }
+/// }
+///
+/// fn run(s: &str) -> Result<usize> {
+///     let s = validate(s)?;
+///     Ok(s.len())
+/// }
+///
+/// assert_eq!(run("foo"), Ok(3));
+/// assert_eq!(run(""), Err(ExitCode::DataErr));
+/// ```
 pub type Result<T> = core::result::Result<T, ExitCode>;
 
 impl<T> From<Result<T>> for ExitCode {
@@ -43,6 +81,127 @@ impl<T> From<Result<T>> for ExitCode {
     }
 }
 
+impl ExitCode {
+    /// Replaces the error of `result` with `code`.
+    ///
+    /// This is equivalent to `result.map_err(|_| code)`, and is useful when the
+    /// `ExitCode` does not depend on the error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] with `code` if `result` is [`Err`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(
+    ///     ExitCode::map_err_code("42".parse::<u8>(), ExitCode::DataErr),
+    ///     Ok(42)
+    /// );
+    /// assert_eq!(
+    ///     ExitCode::map_err_code("a".parse::<u8>(), ExitCode::DataErr),
+    ///     Err(ExitCode::DataErr)
+    /// );
+    /// ```
+    #[inline]
+    pub fn map_err_code<T, E>(result: core::result::Result<T, E>, code: Self) -> Result<T> {
+        result.map_err(|_| code)
+    }
+
+    /// Returns the `ExitCode` corresponding to `result` without consuming it.
+    ///
+    /// This is the borrowing counterpart of the [`From<Result<T>>`]
+    /// implementation. Returns [`ExitCode::Ok`] if `result` is [`Ok`],
+    /// otherwise returns the `ExitCode` contained in the [`Err`] variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let result = Ok::<_, ExitCode>(String::from("foo"));
+    /// assert_eq!(ExitCode::code_of(&result), ExitCode::Ok);
+    /// assert_eq!(result.unwrap(), "foo");
+    ///
+    /// let result = Err::<String, _>(ExitCode::DataErr);
+    /// assert_eq!(ExitCode::code_of(&result), ExitCode::DataErr);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn code_of<T>(result: &Result<T>) -> Self {
+        match result {
+            Ok(_) => Self::Ok,
+            Err(code) => *code,
+        }
+    }
+
+    /// Converts this `ExitCode` into a [`Result<()>`].
+    ///
+    /// This is the inverse of the [`From<Result<T>>`] implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] with this `ExitCode` if it is not [`ExitCode::Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.into_result(), Ok(()));
+    /// assert_eq!(ExitCode::DataErr.into_result(), Err(ExitCode::DataErr));
+    /// ```
+    #[inline]
+    pub const fn into_result(self) -> Result<()> {
+        match self {
+            Self::Ok => Ok(()),
+            code => Err(code),
+        }
+    }
+
+    /// Converts this `ExitCode` into a [`ControlFlow`].
+    ///
+    /// Returns [`ControlFlow::Continue`] if this is [`ExitCode::Ok`], otherwise
+    /// returns [`ControlFlow::Break`] with this `ExitCode`. This is useful for
+    /// stopping an iteration at the first failure, e.g., with
+    /// [`Iterator::try_for_each`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// #
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.into_control_flow(), ControlFlow::Continue(()));
+    /// assert_eq!(
+    ///     ExitCode::DataErr.into_control_flow(),
+    ///     ControlFlow::Break(ExitCode::DataErr)
+    /// );
+    ///
+    /// let steps = [ExitCode::Ok, ExitCode::NoInput, ExitCode::IoErr];
+    /// assert_eq!(
+    ///     steps.into_iter().try_for_each(ExitCode::into_control_flow),
+    ///     ControlFlow::Break(ExitCode::NoInput)
+    /// );
+    /// ```
+    ///
+    /// [`ControlFlow`]: core::ops::ControlFlow
+    /// [`ControlFlow::Continue`]: core::ops::ControlFlow::Continue
+    /// [`ControlFlow::Break`]: core::ops::ControlFlow::Break
+    #[inline]
+    pub const fn into_control_flow(self) -> core::ops::ControlFlow<Self> {
+        use core::ops::ControlFlow;
+
+        match self {
+            Self::Ok => ControlFlow::Continue(()),
+            code => ControlFlow::Break(code),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::any;
@@ -187,4 +346,201 @@ mod tests {
             ExitCode::Config
         );
     }
+
+    #[test]
+    fn question_mark_with_exit_code() {
+        fn f(code: ExitCode) -> Result<()> {
+            Err(code)?;
+            Ok(())
+        }
+
+        assert_eq!(f(ExitCode::Usage), Err(ExitCode::Usage));
+        assert_eq!(f(ExitCode::Config), Err(ExitCode::Config));
+    }
+
+    #[test]
+    fn question_mark_with_into_exit_code() {
+        struct Error;
+
+        impl From<Error> for ExitCode {
+            fn from(_: Error) -> Self {
+                Self::DataErr
+            }
+        }
+
+        fn f(value: core::result::Result<u8, Error>) -> Result<u8> {
+            let value = value?;
+            Ok(value)
+        }
+
+        assert_eq!(f(Ok(42)), Ok(42));
+        assert_eq!(f(Err(Error)), Err(ExitCode::DataErr));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn question_mark_with_io_error() {
+        use std::io;
+
+        fn f(kind: io::ErrorKind) -> Result<()> {
+            Err(io::Error::from(kind))?;
+            Ok(())
+        }
+
+        assert_eq!(f(io::ErrorKind::NotFound), Err(ExitCode::NoInput));
+        assert_eq!(f(io::ErrorKind::PermissionDenied), Err(ExitCode::NoPerm));
+    }
+
+    #[test]
+    fn map_err_code_when_ok() {
+        assert_eq!(
+            ExitCode::map_err_code(Ok::<_, ()>(42), ExitCode::DataErr),
+            Ok(42)
+        );
+        assert_eq!(
+            ExitCode::map_err_code(Ok::<_, ExitCode>(()), ExitCode::DataErr),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn map_err_code_when_err() {
+        assert_eq!(
+            ExitCode::map_err_code(Err::<u8, _>(()), ExitCode::DataErr),
+            Err(ExitCode::DataErr)
+        );
+        assert_eq!(
+            ExitCode::map_err_code(Err::<u8, _>(ExitCode::Usage), ExitCode::Config),
+            Err(ExitCode::Config)
+        );
+        assert_eq!(
+            ExitCode::map_err_code("a".parse::<u8>(), ExitCode::DataErr),
+            Err(ExitCode::DataErr)
+        );
+    }
+
+    #[test]
+    fn code_of_when_ok() {
+        assert_eq!(ExitCode::code_of(&Ok::<(), ExitCode>(())), ExitCode::Ok);
+        assert_eq!(ExitCode::code_of(&Ok::<u8, ExitCode>(42)), ExitCode::Ok);
+    }
+
+    #[test]
+    fn code_of_when_err() {
+        assert_eq!(
+            ExitCode::code_of(&Err::<(), ExitCode>(ExitCode::Usage)),
+            ExitCode::Usage
+        );
+        assert_eq!(
+            ExitCode::code_of(&Err::<u8, ExitCode>(ExitCode::DataErr)),
+            ExitCode::DataErr
+        );
+        assert_eq!(
+            ExitCode::code_of(&Err::<u8, ExitCode>(ExitCode::Config)),
+            ExitCode::Config
+        );
+    }
+
+    #[test]
+    fn code_of_does_not_consume() {
+        use alloc::string::String;
+
+        let result: Result<String> = Ok(String::from("foo"));
+        assert_eq!(ExitCode::code_of(&result), ExitCode::Ok);
+        assert_eq!(result, Ok(String::from("foo")));
+    }
+
+    #[test]
+    fn code_of_matches_from() {
+        for code in ExitCode::iter() {
+            let result: Result<()> = Err(code);
+            assert_eq!(ExitCode::code_of(&result), ExitCode::from(result));
+        }
+    }
+
+    #[test]
+    const fn code_of_is_const_fn() {
+        const _: ExitCode = ExitCode::code_of(&Err::<(), ExitCode>(ExitCode::Usage));
+    }
+
+    #[test]
+    fn into_result() {
+        assert_eq!(ExitCode::Ok.into_result(), Ok(()));
+        assert_eq!(ExitCode::Usage.into_result(), Err(ExitCode::Usage));
+        assert_eq!(ExitCode::DataErr.into_result(), Err(ExitCode::DataErr));
+        assert_eq!(ExitCode::NoInput.into_result(), Err(ExitCode::NoInput));
+        assert_eq!(ExitCode::NoUser.into_result(), Err(ExitCode::NoUser));
+        assert_eq!(ExitCode::NoHost.into_result(), Err(ExitCode::NoHost));
+        assert_eq!(
+            ExitCode::Unavailable.into_result(),
+            Err(ExitCode::Unavailable)
+        );
+        assert_eq!(ExitCode::Software.into_result(), Err(ExitCode::Software));
+        assert_eq!(ExitCode::OsErr.into_result(), Err(ExitCode::OsErr));
+        assert_eq!(ExitCode::OsFile.into_result(), Err(ExitCode::OsFile));
+        assert_eq!(ExitCode::CantCreat.into_result(), Err(ExitCode::CantCreat));
+        assert_eq!(ExitCode::IoErr.into_result(), Err(ExitCode::IoErr));
+        assert_eq!(ExitCode::TempFail.into_result(), Err(ExitCode::TempFail));
+        assert_eq!(ExitCode::Protocol.into_result(), Err(ExitCode::Protocol));
+        assert_eq!(ExitCode::NoPerm.into_result(), Err(ExitCode::NoPerm));
+        assert_eq!(ExitCode::Config.into_result(), Err(ExitCode::Config));
+    }
+
+    #[test]
+    fn into_result_round_trip() {
+        for code in ExitCode::iter() {
+            assert_eq!(ExitCode::from(code.into_result()), code);
+        }
+    }
+
+    #[test]
+    const fn into_result_is_const_fn() {
+        const _: Result<()> = ExitCode::Usage.into_result();
+    }
+
+    #[test]
+    fn into_control_flow() {
+        use core::ops::ControlFlow;
+
+        assert_eq!(ExitCode::Ok.into_control_flow(), ControlFlow::Continue(()));
+        assert_eq!(
+            ExitCode::Usage.into_control_flow(),
+            ControlFlow::Break(ExitCode::Usage)
+        );
+        assert_eq!(
+            ExitCode::DataErr.into_control_flow(),
+            ControlFlow::Break(ExitCode::DataErr)
+        );
+        assert_eq!(
+            ExitCode::Config.into_control_flow(),
+            ControlFlow::Break(ExitCode::Config)
+        );
+        for code in ExitCode::failures() {
+            assert_eq!(code.into_control_flow(), ControlFlow::Break(code));
+        }
+    }
+
+    #[test]
+    fn into_control_flow_with_try_for_each() {
+        use core::ops::ControlFlow;
+
+        assert_eq!(
+            [ExitCode::Ok, ExitCode::Ok]
+                .into_iter()
+                .try_for_each(ExitCode::into_control_flow),
+            ControlFlow::Continue(())
+        );
+
+        let mut steps = [ExitCode::Ok, ExitCode::NoInput, ExitCode::IoErr].into_iter();
+        assert_eq!(
+            steps.try_for_each(ExitCode::into_control_flow),
+            ControlFlow::Break(ExitCode::NoInput)
+        );
+        assert_eq!(steps.next(), Some(ExitCode::IoErr));
+    }
+
+    #[test]
+    const fn into_control_flow_is_const_fn() {
+        const _: core::ops::ControlFlow<ExitCode> = ExitCode::Usage.into_control_flow();
+    }
 }
@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Implementations of [`serde`] traits for [`ExitCode`].
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::ExitCode;
+
+impl Serialize for ExitCode {
+    /// Serializes this `ExitCode` as its integer representation.
+    ///
+    /// To serialize as the variant name instead, use
+    /// [`serde_name`](crate::serde_name).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(serde_json::to_string(&ExitCode::DataErr).unwrap(), "65");
+    /// ```
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(u8::from(*self))
+    }
+}
+
+impl<'de> Deserialize<'de> for ExitCode {
+    /// Deserializes an `ExitCode` from its integer representation.
+    ///
+    /// To deserialize from the variant name instead, use
+    /// [`serde_name`](crate::serde_name).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(
+    ///     serde_json::from_str::<ExitCode>("65").unwrap(),
+    ///     ExitCode::DataErr
+    /// );
+    /// assert!(serde_json::from_str::<ExitCode>("79").is_err());
+    /// ```
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = u8::deserialize(deserializer)?;
+        Self::try_from(code).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        assert_eq!(serde_json::to_string(&ExitCode::Ok).unwrap(), "0");
+        assert_eq!(serde_json::to_string(&ExitCode::Usage).unwrap(), "64");
+        assert_eq!(serde_json::to_string(&ExitCode::DataErr).unwrap(), "65");
+        assert_eq!(serde_json::to_string(&ExitCode::Config).unwrap(), "78");
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(serde_json::from_str::<ExitCode>("0").unwrap(), ExitCode::Ok);
+        assert_eq!(
+            serde_json::from_str::<ExitCode>("64").unwrap(),
+            ExitCode::Usage
+        );
+        assert_eq!(
+            serde_json::from_str::<ExitCode>("65").unwrap(),
+            ExitCode::DataErr
+        );
+        assert_eq!(
+            serde_json::from_str::<ExitCode>("78").unwrap(),
+            ExitCode::Config
+        );
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        assert!(serde_json::from_str::<ExitCode>("1").is_err());
+        assert!(serde_json::from_str::<ExitCode>("79").is_err());
+        assert!(serde_json::from_str::<ExitCode>("256").is_err());
+        assert!(serde_json::from_str::<ExitCode>("-1").is_err());
+        assert!(serde_json::from_str::<ExitCode>(r#""DataErr""#).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        for code in [
+            ExitCode::Ok,
+            ExitCode::Usage,
+            ExitCode::DataErr,
+            ExitCode::NoInput,
+            ExitCode::NoUser,
+            ExitCode::NoHost,
+            ExitCode::Unavailable,
+            ExitCode::Software,
+            ExitCode::OsErr,
+            ExitCode::OsFile,
+            ExitCode::CantCreat,
+            ExitCode::IoErr,
+            ExitCode::TempFail,
+            ExitCode::Protocol,
+            ExitCode::NoPerm,
+            ExitCode::Config,
+        ] {
+            let json = serde_json::to_string(&code).unwrap();
+            assert_eq!(serde_json::from_str::<ExitCode>(&json).unwrap(), code);
+        }
+    }
+}
@@ -0,0 +1,350 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A set of [`ExitCode`]s.
+
+use super::ExitCode;
+
+/// `ExitCodeSet` is a set of [`ExitCode`]s represented as a bitset.
+///
+/// Since every system exit code is assigned a single bit, membership tests
+/// and set operations are performed in constant time without allocating.
+///
+/// # Examples
+///
+/// ```
+/// # use sysexits::{ExitCode, ExitCodeSet};
+/// #
+/// const LOOKUP_FAILURES: ExitCodeSet = ExitCodeSet::new()
+///     .insert(ExitCode::NoInput)
+///     .insert(ExitCode::NoUser)
+///     .insert(ExitCode::NoHost);
+///
+/// assert!(LOOKUP_FAILURES.contains(ExitCode::NoUser));
+/// assert!(!LOOKUP_FAILURES.contains(ExitCode::IoErr));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ExitCodeSet(u32);
+
+impl ExitCodeSet {
+    /// Creates an empty `ExitCodeSet`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitCodeSet};
+    /// #
+    /// let set = ExitCodeSet::new();
+    /// assert!(set.is_empty());
+    /// assert!(!set.contains(ExitCode::Ok));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Returns the bit assigned to `code`.
+    ///
+    /// [`ExitCode::Ok`] is assigned the least significant bit, and
+    /// [`ExitCode::Usage`] through [`ExitCode::Config`] are assigned the
+    /// following bits in order.
+    const fn bit(code: ExitCode) -> u32 {
+        match code {
+            ExitCode::Ok => 1,
+            code => 1 << (code as u8 - 63),
+        }
+    }
+
+    /// Returns a new set with `code` added to this set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitCodeSet};
+    /// #
+    /// let set = ExitCodeSet::new().insert(ExitCode::Usage);
+    /// assert!(set.contains(ExitCode::Usage));
+    /// assert_eq!(set.insert(ExitCode::Usage), set);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn insert(self, code: ExitCode) -> Self {
+        Self(self.0 | Self::bit(code))
+    }
+
+    /// Returns [`true`] if this set contains `code`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitCodeSet};
+    /// #
+    /// let set = ExitCodeSet::new().insert(ExitCode::IoErr);
+    /// assert!(set.contains(ExitCode::IoErr));
+    /// assert!(!set.contains(ExitCode::OsErr));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn contains(self, code: ExitCode) -> bool {
+        self.0 & Self::bit(code) != 0
+    }
+
+    /// Returns the union of this set and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitCodeSet};
+    /// #
+    /// let a = ExitCodeSet::new().insert(ExitCode::Usage);
+    /// let b = ExitCodeSet::new().insert(ExitCode::Config);
+    /// let set = a.union(b);
+    /// assert!(set.contains(ExitCode::Usage));
+    /// assert!(set.contains(ExitCode::Config));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns [`true`] if this set contains no system exit codes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitCodeSet};
+    /// #
+    /// assert!(ExitCodeSet::new().is_empty());
+    /// assert!(!ExitCodeSet::new().insert(ExitCode::Ok).is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the number of system exit codes in this set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitCodeSet};
+    /// #
+    /// let set = ExitCodeSet::new()
+    ///     .insert(ExitCode::Usage)
+    ///     .insert(ExitCode::Usage)
+    ///     .insert(ExitCode::Config);
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns an iterator over the system exit codes in this set, in
+    /// ascending order of the integer representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitCodeSet};
+    /// #
+    /// let set = ExitCodeSet::new()
+    ///     .insert(ExitCode::Config)
+    ///     .insert(ExitCode::Ok)
+    ///     .insert(ExitCode::Usage);
+    /// assert!(set
+    ///     .iter()
+    ///     .eq([ExitCode::Ok, ExitCode::Usage, ExitCode::Config]));
+    /// ```
+    #[inline]
+    pub fn iter(self) -> impl Iterator<Item = ExitCode> {
+        ExitCode::iter().filter(move |&code| self.contains(code))
+    }
+}
+
+impl FromIterator<ExitCode> for ExitCodeSet {
+    /// Collects system exit codes into an `ExitCodeSet`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::{ExitCode, ExitCodeSet};
+    /// #
+    /// let set: ExitCodeSet = [ExitCode::Usage, ExitCode::Config].into_iter().collect();
+    /// assert_eq!(
+    ///     set,
+    ///     ExitCodeSet::new()
+    ///         .insert(ExitCode::Usage)
+    ///         .insert(ExitCode::Config)
+    /// );
+    /// ```
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = ExitCode>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::new(), Self::insert)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::iter;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let set = ExitCodeSet::new();
+        assert!(set.is_empty());
+        assert_eq!(set.len(), 0);
+        assert!(ExitCode::iter().all(|code| !set.contains(code)));
+    }
+
+    #[test]
+    const fn new_is_const_fn() {
+        const _: ExitCodeSet = ExitCodeSet::new();
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(ExitCodeSet::default(), ExitCodeSet::new());
+    }
+
+    #[test]
+    fn insert() {
+        let set = ExitCodeSet::new()
+            .insert(ExitCode::NoInput)
+            .insert(ExitCode::NoUser)
+            .insert(ExitCode::NoHost);
+        assert_eq!(set.len(), 3);
+        assert!(!set.contains(ExitCode::Ok));
+        assert!(!set.contains(ExitCode::Usage));
+        assert!(!set.contains(ExitCode::DataErr));
+        assert!(set.contains(ExitCode::NoInput));
+        assert!(set.contains(ExitCode::NoUser));
+        assert!(set.contains(ExitCode::NoHost));
+        assert!(!set.contains(ExitCode::Unavailable));
+        assert!(!set.contains(ExitCode::Software));
+        assert!(!set.contains(ExitCode::OsErr));
+        assert!(!set.contains(ExitCode::OsFile));
+        assert!(!set.contains(ExitCode::CantCreat));
+        assert!(!set.contains(ExitCode::IoErr));
+        assert!(!set.contains(ExitCode::TempFail));
+        assert!(!set.contains(ExitCode::Protocol));
+        assert!(!set.contains(ExitCode::NoPerm));
+        assert!(!set.contains(ExitCode::Config));
+    }
+
+    #[test]
+    fn insert_is_idempotent() {
+        let set = ExitCodeSet::new().insert(ExitCode::Software);
+        assert_eq!(set.insert(ExitCode::Software), set);
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    const fn insert_is_const_fn() {
+        const _: ExitCodeSet = ExitCodeSet::new().insert(ExitCode::Ok);
+    }
+
+    #[test]
+    fn contains_single() {
+        for code in ExitCode::iter() {
+            let set = ExitCodeSet::new().insert(code);
+            assert_eq!(set.len(), 1);
+            for other in ExitCode::iter() {
+                assert_eq!(set.contains(other), code == other);
+            }
+        }
+    }
+
+    #[test]
+    const fn contains_is_const_fn() {
+        const _: bool = ExitCodeSet::new().contains(ExitCode::Ok);
+    }
+
+    #[test]
+    fn union() {
+        let a = ExitCodeSet::new()
+            .insert(ExitCode::Ok)
+            .insert(ExitCode::Usage);
+        let b = ExitCodeSet::new()
+            .insert(ExitCode::Usage)
+            .insert(ExitCode::Config);
+        let set = a.union(b);
+        assert_eq!(set, b.union(a));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(ExitCode::Ok));
+        assert!(set.contains(ExitCode::Usage));
+        assert!(set.contains(ExitCode::Config));
+        assert!(!set.contains(ExitCode::IoErr));
+    }
+
+    #[test]
+    fn union_with_empty() {
+        let set = ExitCodeSet::new().insert(ExitCode::IoErr);
+        assert_eq!(set.union(ExitCodeSet::new()), set);
+        assert_eq!(ExitCodeSet::new().union(set), set);
+    }
+
+    #[test]
+    const fn union_is_const_fn() {
+        const _: ExitCodeSet = ExitCodeSet::new().union(ExitCodeSet::new());
+    }
+
+    #[test]
+    const fn is_empty_is_const_fn() {
+        const _: bool = ExitCodeSet::new().is_empty();
+    }
+
+    #[test]
+    fn len_when_all() {
+        assert_eq!(ExitCode::iter().collect::<ExitCodeSet>().len(), 16);
+    }
+
+    #[test]
+    const fn len_is_const_fn() {
+        const _: usize = ExitCodeSet::new().len();
+    }
+
+    #[test]
+    fn iter() {
+        let set = ExitCodeSet::new()
+            .insert(ExitCode::Config)
+            .insert(ExitCode::Ok)
+            .insert(ExitCode::IoErr);
+        assert!(set
+            .iter()
+            .eq([ExitCode::Ok, ExitCode::IoErr, ExitCode::Config]));
+    }
+
+    #[test]
+    fn iter_when_empty() {
+        assert_eq!(ExitCodeSet::new().iter().count(), 0);
+    }
+
+    #[test]
+    fn iter_when_all() {
+        assert!(ExitCode::iter()
+            .collect::<ExitCodeSet>()
+            .iter()
+            .eq(ExitCode::iter()));
+    }
+
+    #[test]
+    fn from_iter() {
+        assert_eq!(iter::empty().collect::<ExitCodeSet>(), ExitCodeSet::new());
+        assert_eq!(
+            [ExitCode::Usage, ExitCode::Config, ExitCode::Usage]
+                .into_iter()
+                .collect::<ExitCodeSet>(),
+            ExitCodeSet::new()
+                .insert(ExitCode::Usage)
+                .insert(ExitCode::Config)
+        );
+    }
+}
@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Integration of [`ExitCode`] with [`anstyle`].
+
+use core::fmt;
+
+use anstyle::{AnsiColor, Color, Style};
+
+use super::ExitCode;
+
+struct Styled(ExitCode);
+
+impl Styled {
+    const fn style(&self) -> Style {
+        let color = if self.0.is_success() {
+            AnsiColor::Green
+        } else {
+            AnsiColor::Red
+        };
+        Style::new().fg_color(Some(Color::Ansi(color)))
+    }
+}
+
+impl fmt::Display for Styled {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = self.style();
+        write!(f, "{style}{}{style:#}", u8::from(self.0))
+    }
+}
+
+impl ExitCode {
+    /// Returns a value which displays the numeric value of this system exit
+    /// code with an ANSI style.
+    ///
+    /// The value is rendered in green if this is [`ExitCode::Ok`], and in red
+    /// otherwise. Since only the SGR escape sequences are emitted, they can be
+    /// stripped to plain text when the output does not support styling (e.g.,
+    /// by writing through [`anstream`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// assert_eq!(ExitCode::Ok.styled().to_string(), "\x1b[32m0\x1b[0m");
+    /// assert_eq!(ExitCode::Usage.styled().to_string(), "\x1b[31m64\x1b[0m");
+    /// ```
+    ///
+    /// [`anstream`]: https://docs.rs/anstream
+    #[must_use]
+    #[inline]
+    pub const fn styled(self) -> impl fmt::Display {
+        Styled(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn styled() {
+        assert_eq!(format!("{}", ExitCode::Ok.styled()), "\x1b[32m0\x1b[0m");
+        assert_eq!(format!("{}", ExitCode::Usage.styled()), "\x1b[31m64\x1b[0m");
+        assert_eq!(
+            format!("{}", ExitCode::Software.styled()),
+            "\x1b[31m70\x1b[0m"
+        );
+        assert_eq!(
+            format!("{}", ExitCode::Config.styled()),
+            "\x1b[31m78\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn styled_contains_number() {
+        let styled = format!("{}", ExitCode::Unavailable.styled());
+        assert!(styled.starts_with("\x1b[31m"));
+        assert!(styled.ends_with("\x1b[0m"));
+        assert_eq!(styled.replace("\x1b[31m", "").replace("\x1b[0m", ""), "69");
+    }
+}
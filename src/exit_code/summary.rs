@@ -0,0 +1,110 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A summary of multiple [`ExitCode`]s.
+
+use core::fmt;
+
+use super::ExitCode;
+
+/// `Summary` is a wrapper type for showing the number of occurrences of each
+/// [`ExitCode`] in a slice.
+///
+/// Each system exit code which occurs at least once is shown as
+/// `<name>×<count>` (see [`ExitCode::as_str`]), in ascending order of the
+/// integer representation, separated by `, `. If the slice is empty, nothing
+/// is shown.
+///
+/// This does not allocate.
+///
+/// # Examples
+///
+/// ```
+/// # use sysexits::{ExitCode, Summary};
+/// #
+/// let codes = [ExitCode::Ok, ExitCode::DataErr, ExitCode::Ok, ExitCode::Ok];
+/// assert_eq!(format!("{}", Summary(&codes)), "Ok×3, DataErr×1");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Summary<'a>(pub &'a [ExitCode]);
+
+impl fmt::Display for Summary<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut is_first = true;
+        for code in ExitCode::iter() {
+            let count = self.0.iter().filter(|&&c| c == code).count();
+            if count == 0 {
+                continue;
+            }
+            if !is_first {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}×{count}", code.as_str())?;
+            is_first = false;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone() {
+        let codes = [ExitCode::Ok];
+        assert_eq!(Summary(&codes).clone(), Summary(&codes));
+    }
+
+    #[test]
+    fn copy() {
+        let codes = [ExitCode::Ok];
+        let a = Summary(&codes);
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Summary(&[ExitCode::Ok, ExitCode::Usage])),
+            "Summary([Ok, Usage])"
+        );
+    }
+
+    #[test]
+    fn display() {
+        let codes = [
+            ExitCode::Config,
+            ExitCode::Ok,
+            ExitCode::DataErr,
+            ExitCode::Ok,
+            ExitCode::Config,
+            ExitCode::Ok,
+        ];
+        assert_eq!(format!("{}", Summary(&codes)), "Ok×3, DataErr×1, Config×2");
+    }
+
+    #[test]
+    fn display_single() {
+        assert_eq!(format!("{}", Summary(&[ExitCode::IoErr])), "IoErr×1");
+    }
+
+    #[test]
+    fn display_when_empty() {
+        assert_eq!(format!("{}", Summary(&[])), "");
+    }
+
+    #[test]
+    fn display_when_all() {
+        let codes = ExitCode::VARIANTS;
+        assert_eq!(
+            format!("{}", Summary(codes)),
+            "Ok×1, Usage×1, DataErr×1, NoInput×1, NoUser×1, NoHost×1, Unavailable×1, \
+             Software×1, OsErr×1, OsFile×1, CantCreat×1, IoErr×1, TempFail×1, Protocol×1, \
+             NoPerm×1, Config×1"
+        );
+    }
+}
@@ -0,0 +1,108 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Integration of [`ExitCode`] with [`tracing`].
+
+use tracing::Span;
+
+use super::ExitCode;
+
+impl ExitCode {
+    /// Records this system exit code to `span` as structured fields.
+    ///
+    /// The integer representation is recorded as the `code` field, and the
+    /// alias of the name (see [`ExitCode::name`]) is recorded as the
+    /// `code_name` field.
+    /// These fields must be declared when `span` is created, for example, with
+    /// [`tracing::field::Empty`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sysexits::ExitCode;
+    /// #
+    /// let span = tracing::info_span!(
+    ///     "run",
+    ///     code = tracing::field::Empty,
+    ///     code_name = tracing::field::Empty
+    /// );
+    /// ExitCode::DataErr.record(&span);
+    /// ```
+    #[inline]
+    pub fn record(self, span: &Span) {
+        span.record("code", self.to_u8());
+        span.record("code_name", self.name());
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::{sync::Arc, vec::Vec};
+    use std::{
+        io,
+        string::String,
+        sync::{Mutex, PoisonError},
+    };
+
+    use tracing::field;
+    use tracing_subscriber::fmt::{format::FmtSpan, MakeWriter};
+
+    use super::*;
+
+    #[derive(Clone, Debug, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Buffer {
+        fn contents(&self) -> String {
+            let buf = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+            String::from_utf8_lossy(&buf).into_owned()
+        }
+    }
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl MakeWriter<'_> for Buffer {
+        type Writer = Self;
+
+        fn make_writer(&self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn record(code: ExitCode) -> String {
+        let buf = Buffer::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(buf.clone())
+            .with_ansi(false)
+            .with_span_events(FmtSpan::CLOSE)
+            .finish();
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("run", code = field::Empty, code_name = field::Empty);
+            code.record(&span);
+        });
+        buf.contents()
+    }
+
+    #[test]
+    fn record_exit_code() {
+        let output = record(ExitCode::DataErr);
+        assert!(output.contains("code=65"), "{output}");
+        assert!(output.contains("code_name=\"EX_DATAERR\""), "{output}");
+
+        let output = record(ExitCode::Ok);
+        assert!(output.contains("code=0"), "{output}");
+        assert!(output.contains("code_name=\"EX_OK\""), "{output}");
+    }
+}
@@ -51,13 +51,32 @@
 // Lint levels of rustc.
 #![deny(missing_docs)]
 
-#[cfg(test)]
-#[macro_use]
+#[cfg(any(feature = "alloc", test))]
+#[cfg_attr(test, macro_use)]
 extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
 pub mod error;
 mod exit_code;
+#[cfg(feature = "serde")]
+pub mod serde_name;
+#[cfg(all(feature = "std", any(feature = "test_helpers", test)))]
+pub mod test_helpers;
 
-pub use crate::exit_code::{result::Result, ExitCode};
+#[cfg(feature = "miette")]
+pub use crate::exit_code::diagnostic::ExitCodeDiagnostic;
+#[cfg(feature = "std")]
+pub use crate::exit_code::exit::{exit_failure, exit_success, ResultExitExt};
+#[cfg(feature = "alloc")]
+pub use crate::exit_code::exit_error::ExitError;
+#[cfg(feature = "std")]
+pub use crate::exit_code::io_error_mapper::IoErrorMapper;
+#[cfg(feature = "std")]
+pub use crate::exit_code::main_result::MainResult;
+#[cfg(feature = "std")]
+pub use crate::exit_code::report::{from_reported, Reported};
+pub use crate::exit_code::{
+    category::Category, descriptor::ExitCodeDescriptor, iter::ResultIteratorExt, names,
+    result::Result, set::ExitCodeSet, summary::Summary, ExitCode,
+};
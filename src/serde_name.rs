@@ -0,0 +1,183 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Serialization and deserialization of [`ExitCode`] as the variant name.
+//!
+//! By default, [`ExitCode`] is serialized as its integer representation (e.g.,
+//! `65`). This module can be used with `#[serde(with =
+//! "sysexits::serde_name")]` to serialize it as the variant name (e.g.,
+//! `"DataErr"`) instead.
+//!
+//! When deserializing, the macro name defined in [`<sysexits.h>`] (e.g.,
+//! `"EX_DATAERR"`) is also accepted.
+//!
+//! # Examples
+//!
+//! ```
+//! # use serde::{Deserialize, Serialize};
+//! # use sysexits::ExitCode;
+//! #
+//! #[derive(Debug, Deserialize, PartialEq, Serialize)]
+//! struct Outcome {
+//!     code: ExitCode,
+//!     #[serde(with = "sysexits::serde_name")]
+//!     name: ExitCode,
+//! }
+//!
+//! let outcome = Outcome {
+//!     code: ExitCode::DataErr,
+//!     name: ExitCode::DataErr,
+//! };
+//! let json = serde_json::to_string(&outcome).unwrap();
+//! assert_eq!(json, r#"{"code":65,"name":"DataErr"}"#);
+//! assert_eq!(serde_json::from_str::<Outcome>(&json).unwrap(), outcome);
+//! ```
+//!
+//! [`<sysexits.h>`]: https://man.openbsd.org/sysexits
+
+use core::fmt;
+
+use serde::{de, Deserializer, Serializer};
+
+use crate::ExitCode;
+
+/// Serializes an [`ExitCode`] as the variant name (see [`ExitCode::as_str`]).
+///
+/// # Errors
+///
+/// Returns [`Err`] if the serializer fails.
+///
+/// # Examples
+///
+/// ```
+/// # use sysexits::ExitCode;
+/// #
+/// let mut buf = Vec::new();
+/// let mut serializer = serde_json::Serializer::new(&mut buf);
+/// sysexits::serde_name::serialize(&ExitCode::DataErr, &mut serializer).unwrap();
+/// assert_eq!(buf, br#""DataErr""#);
+/// ```
+#[inline]
+#[allow(clippy::trivially_copy_pass_by_ref)]
+pub fn serialize<S: Serializer>(code: &ExitCode, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(code.as_str())
+}
+
+/// Deserializes an [`ExitCode`] from the variant name or the macro name (see
+/// [`ExitCode::from_name`]).
+///
+/// # Errors
+///
+/// Returns [`Err`] if the input is not a string or is not a known name.
+///
+/// # Examples
+///
+/// ```
+/// # use sysexits::ExitCode;
+/// #
+/// let mut deserializer = serde_json::Deserializer::from_str(r#""DataErr""#);
+/// assert_eq!(
+///     sysexits::serde_name::deserialize(&mut deserializer).unwrap(),
+///     ExitCode::DataErr
+/// );
+/// ```
+#[inline]
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ExitCode, D::Error> {
+    deserializer.deserialize_str(NameVisitor)
+}
+
+struct NameVisitor;
+
+impl de::Visitor<'_> for NameVisitor {
+    type Value = ExitCode;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the name of a system exit code")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        ExitCode::from_name(value)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Named(#[serde(with = "super")] ExitCode);
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&Named(ExitCode::Ok)).unwrap(),
+            r#""Ok""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Named(ExitCode::Usage)).unwrap(),
+            r#""Usage""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Named(ExitCode::DataErr)).unwrap(),
+            r#""DataErr""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Named(ExitCode::Config)).unwrap(),
+            r#""Config""#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(
+            serde_json::from_str::<Named>(r#""Ok""#).unwrap(),
+            Named(ExitCode::Ok)
+        );
+        assert_eq!(
+            serde_json::from_str::<Named>(r#""DataErr""#).unwrap(),
+            Named(ExitCode::DataErr)
+        );
+        assert_eq!(
+            serde_json::from_str::<Named>(r#""EX_DATAERR""#).unwrap(),
+            Named(ExitCode::DataErr)
+        );
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        assert!(serde_json::from_str::<Named>(r#""dataerr""#).is_err());
+        assert!(serde_json::from_str::<Named>(r#""65""#).is_err());
+        assert!(serde_json::from_str::<Named>(r#""""#).is_err());
+        assert!(serde_json::from_str::<Named>("65").is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        for code in [
+            ExitCode::Ok,
+            ExitCode::Usage,
+            ExitCode::DataErr,
+            ExitCode::NoInput,
+            ExitCode::NoUser,
+            ExitCode::NoHost,
+            ExitCode::Unavailable,
+            ExitCode::Software,
+            ExitCode::OsErr,
+            ExitCode::OsFile,
+            ExitCode::CantCreat,
+            ExitCode::IoErr,
+            ExitCode::TempFail,
+            ExitCode::Protocol,
+            ExitCode::NoPerm,
+            ExitCode::Config,
+        ] {
+            let json = serde_json::to_string(&Named(code)).unwrap();
+            assert_eq!(json, format!(r#""{}""#, code.as_str()));
+            assert_eq!(serde_json::from_str::<Named>(&json).unwrap(), Named(code));
+        }
+    }
+}
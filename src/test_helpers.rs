@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Helpers for testing programs which handle [`ExitCode`](crate::ExitCode).
+
+use std::{
+    format,
+    process::{Command, ExitStatus},
+};
+
+/// Returns an [`ExitStatus`] which has the exit code `code`.
+///
+/// This executes a shell (`sh` on Unix and `cmd` on Windows) which exits with
+/// `code`, so this works on each platform without platform-specific
+/// constructors. If `code` is a system exit code, prefer
+/// [`ExitCode::to_exit_status`](crate::ExitCode::to_exit_status), which does
+/// not execute a child process.
+///
+/// # Panics
+///
+/// Panics if the shell could not be executed.
+///
+/// # Examples
+///
+/// ```
+/// # use sysexits::{test_helpers, ExitCode};
+/// #
+/// let status = test_helpers::exit_status_from_code(65);
+/// assert_eq!(status.code(), Some(65));
+/// assert_eq!(ExitCode::try_from(status).unwrap(), ExitCode::DataErr);
+/// ```
+#[cfg(any(unix, windows))]
+#[must_use]
+#[inline]
+pub fn exit_status_from_code(code: u8) -> ExitStatus {
+    #[cfg(unix)]
+    let mut command = {
+        let mut command = Command::new("sh");
+        command.arg("-c");
+        command
+    };
+    #[cfg(windows)]
+    let mut command = {
+        let mut command = Command::new("cmd");
+        command.arg("/c");
+        command
+    };
+    command.arg(format!("exit {code}")).status().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ExitCode;
+
+    #[cfg(any(unix, windows))]
+    #[test]
+    fn exit_status_from_code() {
+        for code in [0, 1, 63, 64, 65, 78, 79, 255] {
+            let status = super::exit_status_from_code(code);
+            assert_eq!(status.code(), Some(i32::from(code)));
+            assert_eq!(status.success(), code == 0);
+        }
+    }
+
+    #[cfg(any(unix, windows))]
+    #[test]
+    fn exit_status_from_code_round_trip() {
+        for code in ExitCode::iter() {
+            let status = super::exit_status_from_code(code.to_u8());
+            assert_eq!(ExitCode::try_from(status).unwrap(), code);
+        }
+    }
+}
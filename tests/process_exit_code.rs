@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that an [`ExitCode`] reported as [`std::process::ExitCode`] by a real
+//! process is observed as the same `ExitCode` by its parent process.
+//!
+//! Since [`std::process::ExitCode`] is opaque, the only reliable way to check
+//! the conversion into it is to return it from `main`. Therefore, this test
+//! does not use the default test harness, and executes itself as the child
+//! process.
+
+use std::{
+    env,
+    process::{self, Command, ExitStatus},
+};
+
+use sysexits::ExitCode;
+
+const ENV_KEY: &str = "SYSEXITS_TEST_PROCESS_EXIT_CODE";
+
+/// Executes this test in a child process, which returns `code` converted into
+/// [`std::process::ExitCode`] from `main`.
+fn report(code: ExitCode) -> ExitStatus {
+    Command::new(env::current_exe().unwrap())
+        .env(ENV_KEY, code.as_str())
+        .status()
+        .unwrap()
+}
+
+fn round_trip() {
+    for code in ExitCode::iter() {
+        let status = report(code);
+        assert_eq!(status.code(), Some(code.to_i32()));
+        assert_eq!(status.success(), code.is_success());
+        assert_eq!(ExitCode::try_from(status), Ok(code));
+    }
+}
+
+#[cfg(feature = "test_helpers")]
+fn round_trip_matches_exit_status_from_code() {
+    use sysexits::test_helpers::exit_status_from_code as get_exit_status;
+
+    for code in ExitCode::iter() {
+        assert_eq!(report(code), get_exit_status(code.to_u8()));
+    }
+}
+
+fn main() -> process::ExitCode {
+    if let Ok(code) = env::var(ENV_KEY) {
+        return ExitCode::from_name(&code).unwrap().into();
+    }
+
+    round_trip();
+    #[cfg(feature = "test_helpers")]
+    round_trip_matches_exit_status_from_code();
+    process::ExitCode::SUCCESS
+}